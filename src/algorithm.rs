@@ -0,0 +1,1049 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides algorithms over collections of intervals.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::bound::LowerBound;
+use crate::bound::UpperBound;
+use crate::interval::Interval;
+use crate::normalize::IntervalPoint;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::iter::Peekable;
+use std::ops::Sub;
+use std::rc::Rc;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Histogram
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the number of the given points contained in each of the given
+/// bins. The bins must be sorted and disjoint. Points which do not fall into
+/// any bin are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::histogram;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let bins: Vec<Interval<i32>> = Interval::closed(0, 9).bins(5).collect();
+///
+/// assert_eq!(histogram(&bins, vec![1, 3, 5, 8, 9, 12]), [2, 3]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn histogram<T, I>(bins: &[Interval<T>], points: I) -> Vec<usize>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        I: IntoIterator<Item=T>,
+{
+    let mut counts = vec![0; bins.len()];
+    for point in points {
+        if let Ok(idx) = bins.binary_search_by(|bin| bin.cmp_point(&point)) {
+            counts[idx] += 1;
+        }
+    }
+    counts
+}
+
+
+/// Returns the number of the given `Interval`s containing each of the given
+/// query points.
+///
+/// The bounds of the `Interval`s are sorted once, and each point is counted
+/// with a pair of binary searches, for a total cost of `O((n + m) log n)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::stab_counts;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let intervals = [
+///     Interval::closed(0, 10),
+///     Interval::closed(5, 15),
+///     Interval::unbounded_from(12),
+/// ];
+///
+/// assert_eq!(stab_counts(&intervals, &[-1, 0, 5, 11, 13, 20]),
+///     [0, 1, 2, 1, 2, 1]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn stab_counts<T>(intervals: &[Interval<T>], points: &[T]) -> Vec<usize>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    let mut lowers: Vec<LowerBound<&T>> = intervals
+        .iter()
+        .filter_map(Interval::lower_bound_ref)
+        .map(LowerBound)
+        .collect();
+    let mut uppers: Vec<UpperBound<&T>> = intervals
+        .iter()
+        .filter_map(Interval::upper_bound_ref)
+        .map(UpperBound)
+        .collect();
+    lowers.sort();
+    uppers.sort();
+
+    points
+        .iter()
+        .map(|point| {
+            // Count the intervals which begin at or before the point, less
+            // those which end before it.
+            let started = lowers.partition_point(|lower| match lower.0 {
+                Bound::Include(l) => l <= point,
+                Bound::Exclude(l) => l < point,
+                Bound::Infinite   => true,
+            });
+            let ended = uppers.partition_point(|upper| match upper.0 {
+                Bound::Include(u) => u < point,
+                Bound::Exclude(u) => u <= point,
+                Bound::Infinite   => false,
+            });
+            started - ended
+        })
+        .collect()
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Partition verification
+////////////////////////////////////////////////////////////////////////////////
+/// A violation found while verifying that a collection of `Interval`s
+/// partitions a target `Interval`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PartitionError<T> {
+    /// A part extends beyond the target `Interval`.
+    Excess(Interval<T>),
+    /// Two parts have points in common.
+    Overlap(Interval<T>, Interval<T>),
+    /// A portion of the target `Interval` is not covered by any part.
+    Gap(Interval<T>),
+}
+
+impl<T> std::fmt::Display for PartitionError<T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PartitionError::Excess(ref part)
+                => write!(f, "part {} extends beyond the target", part),
+            PartitionError::Overlap(ref a, ref b)
+                => write!(f, "parts {} and {} overlap", a, b),
+            PartitionError::Gap(ref gap)
+                => write!(f, "target portion {} is not covered", gap),
+        }
+    }
+}
+
+impl<T> std::error::Error for PartitionError<T>
+    where T: std::fmt::Debug + std::fmt::Display
+{}
+
+impl<T> PartitionError<T> {
+    /// Returns the [`PartitionErrorKind`] of the violation.
+    ///
+    /// [`PartitionErrorKind`]: enum.PartitionErrorKind.html
+    pub fn kind(&self) -> PartitionErrorKind {
+        match *self {
+            PartitionError::Excess(..)  => PartitionErrorKind::Excess,
+            PartitionError::Overlap(..) => PartitionErrorKind::Overlap,
+            PartitionError::Gap(..)     => PartitionErrorKind::Gap,
+        }
+    }
+}
+
+/// The kind of a [`PartitionError`], without the offending intervals.
+///
+/// This is what an [`IntervalError::Partition`] carries, as the
+/// `IntervalError` type is not generic over the point type.
+///
+/// [`PartitionError`]: enum.PartitionError.html
+/// [`IntervalError::Partition`]: ../error/enum.IntervalError.html#variant.Partition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartitionErrorKind {
+    /// A part extends beyond the target `Interval`.
+    Excess,
+    /// Two parts have points in common.
+    Overlap,
+    /// A portion of the target `Interval` is not covered by any part.
+    Gap,
+}
+
+impl std::fmt::Display for PartitionErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PartitionErrorKind::Excess
+                => write!(f, "part extends beyond the target"),
+            PartitionErrorKind::Overlap
+                => write!(f, "parts overlap"),
+            PartitionErrorKind::Gap
+                => write!(f, "target portion is not covered"),
+        }
+    }
+}
+
+/// Verifies that the given parts exactly partition the target `Interval`,
+/// covering every point of it without overlapping each other or extending
+/// beyond it. Empty parts are ignored.
+///
+/// # Errors
+///
+/// Returns the first violation found. Parts are examined in order of their
+/// lower bounds, and any [`Excess`] or [`Overlap`] is reported before the
+/// first [`Gap`].
+///
+/// [`Excess`]: enum.PartitionError.html#variant.Excess
+/// [`Overlap`]: enum.PartitionError.html#variant.Overlap
+/// [`Gap`]: enum.PartitionError.html#variant.Gap
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::PartitionError;
+/// # use normalize_interval::algorithm::verify_partition;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let target: Interval<i32> = Interval::closed(0, 99);
+///
+/// let shards = vec![
+///     Interval::closed(50, 99),
+///     Interval::closed(0, 24),
+///     Interval::closed(25, 49),
+/// ];
+/// assert_eq!(verify_partition(&target, shards), Ok(()));
+///
+/// let shards = vec![
+///     Interval::closed(0, 24),
+///     Interval::closed(20, 49),
+///     Interval::closed(50, 99),
+/// ];
+/// assert_eq!(verify_partition(&target, shards),
+///     Err(PartitionError::Overlap(
+///         Interval::closed(0, 24),
+///         Interval::closed(20, 49))));
+///
+/// let shards = vec![
+///     Interval::closed(0, 24),
+///     Interval::closed(50, 99),
+/// ];
+/// assert_eq!(verify_partition(&target, shards),
+///     Err(PartitionError::Gap(Interval::closed(25, 49))));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn verify_partition<T, I>(target: &Interval<T>, parts: I)
+    -> Result<(), PartitionError<T>>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        I: IntoIterator<Item=Interval<T>>,
+{
+    let mut parts: Vec<_> = parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect();
+    parts.sort_by_key(|part| LowerBound(part
+        .lower_bound()
+        .expect("nonempty interval")));
+
+    // After sorting, any overlap must occur between adjacent parts.
+    let mut prev: Option<&Interval<T>> = None;
+    for part in &parts {
+        if &part.intersect(target) != part {
+            return Err(PartitionError::Excess(part.clone()));
+        }
+        if let Some(prev) = prev {
+            if prev.intersects(part) {
+                return Err(PartitionError::Overlap(prev.clone(), part.clone()));
+            }
+        }
+        prev = Some(part);
+    }
+
+    match Interval::union_all(parts).covers(target) {
+        Ok(())    => Ok(()),
+        Err(gaps) => Err(PartitionError::Gap(gaps
+            .interval_iter()
+            .next()
+            .expect("nonempty gaps"))),
+    }
+}
+
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Sorted slice operations
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the `Interval`s containing all points in the first slice which are
+/// not in the second slice.
+///
+/// Both slices must be sorted and disjoint. The result is computed in a single
+/// linear pass over both slices, and is also sorted and disjoint.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::minus_sorted;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = [Interval::closed(0, 10), Interval::closed(20, 30)];
+/// let b = [Interval::closed(3, 5), Interval::closed(8, 22), Interval::point(25)];
+///
+/// assert_eq!(minus_sorted(&a, &b), [
+///     Interval::closed(0, 2),
+///     Interval::closed(6, 7),
+///     Interval::closed(23, 24),
+///     Interval::closed(26, 30),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn minus_sorted<T>(a: &[Interval<T>], b: &[Interval<T>]) -> Vec<Interval<T>>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    let mut result = Vec::with_capacity(a.len());
+    let mut b_idx = 0;
+
+    for interval in a.iter().filter(|interval| !interval.is_empty()) {
+        let mut rest = Some(interval.clone());
+
+        while let (Some(current), Some(sub)) = (rest.as_ref(), b.get(b_idx)) {
+            if sub.is_empty() { b_idx += 1; continue; }
+
+            let current_lower = LowerBound(current
+                .lower_bound_ref()
+                .expect("nonempty interval"));
+            let current_upper = UpperBound(current
+                .upper_bound_ref()
+                .expect("nonempty interval"));
+            let sub_lower = LowerBound(sub
+                .lower_bound_ref()
+                .expect("nonempty interval"));
+            let sub_upper = UpperBound(sub
+                .upper_bound_ref()
+                .expect("nonempty interval"));
+
+            if !current.intersects(sub) {
+                if sub_lower < current_lower {
+                    // The subtracted interval lies entirely below.
+                    b_idx += 1;
+                    continue;
+                }
+                // The subtracted interval lies entirely above.
+                break;
+            }
+
+            // Emit the portion below the subtracted interval and retain the
+            // portion above it.
+            let mut above = None;
+            for piece in current.minus(sub) {
+                let piece_lower = LowerBound(piece
+                    .lower_bound_ref()
+                    .expect("nonempty interval"));
+                if piece_lower < sub_lower {
+                    result.push(piece);
+                } else {
+                    above = Some(piece);
+                }
+            }
+
+            // The subtracted interval may still affect the next interval.
+            if sub_upper <= current_upper { b_idx += 1; }
+            rest = above;
+        }
+
+        if let Some(rest) = rest {
+            result.push(rest);
+        }
+    }
+    result
+}
+
+/// Returns the index of the `Interval` in the given slice which contains the
+/// given point, or `None` if no `Interval` contains it.
+///
+/// The slice must be sorted and disjoint, and the search takes logarithmic
+/// time.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::find_containing;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sorted = [
+///     Interval::closed(0, 4),
+///     Interval::open(10, 15),
+///     Interval::closed(20, 30),
+/// ];
+///
+/// assert_eq!(find_containing(&sorted, &4), Some(0));
+/// assert_eq!(find_containing(&sorted, &10), None);
+/// assert_eq!(find_containing(&sorted, &14), Some(1));
+/// assert_eq!(find_containing(&sorted, &31), None);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn find_containing<T>(sorted: &[Interval<T>], point: &T) -> Option<usize>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    sorted.binary_search_by(|interval| interval.cmp_point(point)).ok()
+}
+
+/// Returns the number of `Interval`s at the start of the given slice which
+/// begin at or before the given point. This is the index at which an
+/// `Interval` beginning just after the point would be inserted.
+///
+/// The slice must be sorted by lower bound and contain no empty `Interval`s,
+/// and the search takes logarithmic time.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::partition_point_by_start;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sorted = [
+///     Interval::closed(0, 4),
+///     Interval::closed(3, 15),
+///     Interval::closed(20, 30),
+/// ];
+///
+/// assert_eq!(partition_point_by_start(&sorted, &-1), 0);
+/// assert_eq!(partition_point_by_start(&sorted, &3), 2);
+/// assert_eq!(partition_point_by_start(&sorted, &19), 2);
+/// assert_eq!(partition_point_by_start(&sorted, &20), 3);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn partition_point_by_start<T>(sorted: &[Interval<T>], point: &T) -> usize
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    sorted.partition_point(|interval| match interval.lower_bound_ref() {
+        Some(Bound::Include(l)) => l <= point,
+        Some(Bound::Exclude(l)) => l < point,
+        _                       => true,
+    })
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// MergeSorted
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator which lazily merges the given streams of `Interval`s
+/// into a single sorted stream, coalescing any `Interval`s which overlap or
+/// are adjacent. Empty `Interval`s are skipped.
+///
+/// Each stream must be sorted by lower bound. Only one `Interval` from each
+/// stream is buffered at a time, so the streams are never materialized.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::merge_sorted;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = vec![Interval::closed(0, 3), Interval::closed(10, 15)];
+/// let b = vec![Interval::closed(2, 5), Interval::closed(6, 8)];
+/// let c = vec![Interval::closed(20, 25)];
+///
+/// let merged: Vec<Interval<i32>> = merge_sorted(vec![a, b, c]).collect();
+/// assert_eq!(merged, [
+///     Interval::closed(0, 8),
+///     Interval::closed(10, 15),
+///     Interval::closed(20, 25),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn merge_sorted<T, S, I>(streams: S) -> MergeSorted<T, I::IntoIter>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        S: IntoIterator<Item=I>,
+        I: IntoIterator<Item=Interval<T>>,
+{
+    MergeSorted {
+        streams: streams
+            .into_iter()
+            .map(|stream| stream.into_iter().peekable())
+            .collect(),
+    }
+}
+
+/// An iterator over the coalesced `Interval`s of several sorted streams. This
+/// `struct` is created by the [`merge_sorted`] function.
+///
+/// [`merge_sorted`]: fn.merge_sorted.html
+#[derive(Debug)]
+pub struct MergeSorted<T, I> where I: Iterator<Item=Interval<T>> {
+    /// The streams being merged.
+    streams: Vec<Peekable<I>>,
+}
+
+impl<T, I> MergeSorted<T, I>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    /// Returns the index of the stream whose next `Interval` has the least
+    /// lower bound, or `None` if all streams are exhausted.
+    fn least_stream(&mut self) -> Option<usize> {
+        let mut least: Option<(usize, LowerBound<&T>)> = None;
+        for (idx, stream) in self.streams.iter_mut().enumerate() {
+            while let Some(true) = stream.peek().map(Interval::is_empty) {
+                let _ = stream.next();
+            }
+            let lower = match stream.peek() {
+                Some(interval) => LowerBound(interval
+                    .lower_bound_ref()
+                    .expect("nonempty interval")),
+                None           => continue,
+            };
+            match least {
+                Some((_, ref l)) if *l <= lower => (),
+                _                               => least = Some((idx, lower)),
+            }
+        }
+        least.map(|(idx, _)| idx)
+    }
+}
+
+impl<T, I> Iterator for MergeSorted<T, I>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.least_stream()?;
+        let mut current = self.streams[idx].next().expect("peeked interval");
+
+        while let Some(idx) = self.least_stream() {
+            let next = self.streams[idx].peek().expect("peeked interval");
+            match coalesce(&current, next) {
+                Some(merged) => {
+                    current = merged;
+                    let _ = self.streams[idx].next();
+                },
+                None         => break,
+            }
+        }
+        Some(current)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// TryMergeSorted
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator which lazily merges the given fallible streams of
+/// `Interval`s into a single sorted stream, as [`merge_sorted`] does. This is
+/// suited to merging sorted chunks read from storage, as only one `Interval`
+/// from each stream is buffered at a time.
+///
+/// The first error encountered in any stream is yielded in place of the
+/// `Interval` being merged, after which the iterator is exhausted.
+///
+/// [`merge_sorted`]: fn.merge_sorted.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::try_merge_sorted;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a: Vec<Result<Interval<i32>, &str>> = vec![
+///     Ok(Interval::closed(0, 3)),
+///     Ok(Interval::closed(10, 15)),
+/// ];
+/// let b = vec![Ok(Interval::closed(2, 5))];
+///
+/// let merged: Result<Vec<_>, _> = try_merge_sorted(vec![a, b]).collect();
+/// assert_eq!(merged, Ok(vec![
+///     Interval::closed(0, 5),
+///     Interval::closed(10, 15),
+/// ]));
+///
+/// let a = vec![Ok(Interval::closed(0, 3)), Err("read failed")];
+/// let b = vec![Ok(Interval::closed(2, 5))];
+///
+/// let merged: Result<Vec<_>, _> = try_merge_sorted(vec![a, b]).collect();
+/// assert_eq!(merged, Err("read failed"));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn try_merge_sorted<T, E, S, I>(streams: S)
+    -> TryMergeSorted<T, E, I::IntoIter>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        S: IntoIterator<Item=I>,
+        I: IntoIterator<Item=Result<Interval<T>, E>>,
+{
+    let error = Rc::new(RefCell::new(None));
+    let merge = merge_sorted(streams
+        .into_iter()
+        .map(|stream| StopOnError {
+            stream: stream.into_iter(),
+            error: error.clone(),
+        }));
+
+    TryMergeSorted { merge, error, done: false }
+}
+
+/// An iterator over the coalesced `Interval`s of several fallible sorted
+/// streams. This `struct` is created by the [`try_merge_sorted`] function.
+///
+/// [`try_merge_sorted`]: fn.try_merge_sorted.html
+#[derive(Debug)]
+pub struct TryMergeSorted<T, E, I>
+    where I: Iterator<Item=Result<Interval<T>, E>>
+{
+    /// The merge over the successful `Interval`s of each stream.
+    merge: MergeSorted<T, StopOnError<I, E>>,
+    /// The first error encountered in any stream.
+    error: Rc<RefCell<Option<E>>>,
+    /// Whether the iterator is exhausted.
+    done: bool,
+}
+
+impl<T, E, I> Iterator for TryMergeSorted<T, E, I>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Result<Interval<T>, E>>,
+{
+    type Item = Result<Interval<T>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.merge.next();
+        if let Some(e) = self.error.borrow_mut().take() {
+            self.done = true;
+            return Some(Err(e));
+        }
+        if next.is_none() {
+            self.done = true;
+        }
+        next.map(Ok)
+    }
+}
+
+/// A stream adapter which ends its stream at the first error, recording the
+/// error for the owning [`TryMergeSorted`].
+///
+/// [`TryMergeSorted`]: struct.TryMergeSorted.html
+#[derive(Debug)]
+struct StopOnError<I, E> {
+    /// The underlying stream.
+    stream: I,
+    /// The error slot shared by all streams being merged.
+    error: Rc<RefCell<Option<E>>>,
+}
+
+impl<T, E, I> Iterator for StopOnError<I, E>
+    where I: Iterator<Item=Result<Interval<T>, E>>
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.borrow().is_some() {
+            return None;
+        }
+        match self.stream.next()? {
+            Ok(interval) => Some(interval),
+            Err(e)       => {
+                *self.error.borrow_mut() = Some(e);
+                None
+            },
+        }
+    }
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Conflict detection
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the ids of every pair of the given tagged `Interval`s which
+/// overlap, found with a sweep over the `Interval`s in order of their lower
+/// bounds. Empty `Interval`s never overlap.
+///
+/// Within each pair, the id of the `Interval` with the lesser lower bound is
+/// given first.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::conflicts;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let bookings = vec![
+///     (Interval::closed(9, 11), "alice"),
+///     (Interval::closed(13, 15), "bob"),
+///     (Interval::closed(10, 12), "carol"),
+///     (Interval::closed(15, 16), "dave"),
+/// ];
+///
+/// assert_eq!(conflicts(bookings), [("alice", "carol"), ("bob", "dave")]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn conflicts<T, K, I>(intervals: I) -> Vec<(K, K)>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        K: Clone,
+        I: IntoIterator<Item=(Interval<T>, K)>,
+{
+    let mut intervals: Vec<_> = intervals
+        .into_iter()
+        .filter(|(interval, _)| !interval.is_empty())
+        .collect();
+    intervals.sort_by(|(a, _), (b, _)| {
+        let a = LowerBound(a.lower_bound_ref().expect("nonempty interval"));
+        let b = LowerBound(b.lower_bound_ref().expect("nonempty interval"));
+        a.cmp(&b)
+    });
+
+    let mut conflicts = Vec::new();
+    let mut active: Vec<&(Interval<T>, K)> = Vec::new();
+    for current in &intervals {
+        // Anything which doesn't reach the current interval can't reach any
+        // later interval either.
+        active.retain(|(interval, _)| interval.intersects(&current.0));
+        for (_, id) in &active {
+            conflicts.push((id.clone(), current.1.clone()));
+        }
+        active.push(current);
+    }
+    conflicts
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval partitioning
+////////////////////////////////////////////////////////////////////////////////
+/// Assigns each of the given `Interval`s to a resource such that no two
+/// overlapping `Interval`s share a resource, using as few resources as
+/// possible. Returns the number of resources needed and the resource index
+/// assigned to each `Interval`, in the order given. Empty `Interval`s are not
+/// assigned a resource.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::min_resources;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let meetings = [
+///     Interval::closed(9, 10),
+///     Interval::closed(9, 12),
+///     Interval::closed(11, 13),
+///     Interval::closed(10, 11),
+///     Interval::empty(),
+/// ];
+///
+/// let (rooms, assigned) = min_resources(&meetings);
+/// assert_eq!(rooms, 3);
+/// assert_eq!(assigned, [Some(0), Some(1), Some(0), Some(2), None]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn min_resources<T>(intervals: &[Interval<T>]) -> (usize, Vec<Option<usize>>)
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    let mut order: Vec<usize> = (0..intervals.len())
+        .filter(|&idx| !intervals[idx].is_empty())
+        .collect();
+    order.sort_by_key(|&idx| LowerBound(intervals[idx]
+        .lower_bound_ref()
+        .expect("nonempty interval")));
+
+    let mut assigned = vec![None; intervals.len()];
+    let mut resources = 0;
+    let mut free: BinaryHeap<Reverse<usize>> = BinaryHeap::new();
+    let mut active: BinaryHeap<Reverse<(UpperBound<&T>, usize)>>
+        = BinaryHeap::new();
+
+    for idx in order {
+        let current = &intervals[idx];
+
+        // Release the resources of any intervals which end before the current
+        // interval begins.
+        while let Some(&Reverse((_, prev))) = active.peek() {
+            if intervals[prev].intersects(current) { break; }
+            let _ = active.pop();
+            free.push(Reverse(assigned[prev].expect("assigned resource")));
+        }
+
+        let resource = match free.pop() {
+            Some(Reverse(resource)) => resource,
+            None                    => { resources += 1; resources - 1 },
+        };
+        assigned[idx] = Some(resource);
+        active.push(Reverse((
+            UpperBound(current.upper_bound_ref().expect("nonempty interval")),
+            idx)));
+    }
+    (resources, assigned)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Coverage counting
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the `Selection` of points covered by at least `k` of the given
+/// `Selection`s, found with a single sweep over their bounds.
+///
+/// With `k` equal to 1 this is the union of the `Selection`s, and with `k`
+/// equal to their number it is their intersection. Every point is covered by
+/// at least zero `Selection`s, so `k` equal to 0 gives the full `Selection`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::algorithm::covered_by_at_least;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let votes: Vec<Selection<i32>> = vec![
+///     Selection::from(Interval::closed(0, 10)),
+///     Selection::from(Interval::closed(5, 15)),
+///     Selection::from(Interval::closed(8, 20)),
+/// ];
+///
+/// let quorum = covered_by_at_least(&votes, 2);
+/// assert_eq!(quorum.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(5, 15),
+/// ]);
+///
+/// let unanimous = covered_by_at_least(&votes, 3);
+/// assert_eq!(unanimous.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(8, 10),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn covered_by_at_least<T>(selections: &[Selection<T>], k: usize)
+    -> Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    if k == 0 {
+        return Selection::full();
+    }
+
+    // Each interval contributes an entry event at its least point and an
+    // exit event just after its greatest point. Exits sort after entries at
+    // the same point.
+    let mut events: Vec<(T, bool)> = Vec::new();
+    for selection in selections {
+        for interval in selection.interval_iter() {
+            if let (Some(l), Some(u)) = (interval.infimum(), interval.supremum())
+            {
+                events.push((l, false));
+                events.push((u, true));
+            }
+        }
+    }
+    events.sort();
+
+    let mut covered = Selection::new();
+    let mut depth = 0;
+    let mut start = None;
+    for (point, is_exit) in events {
+        if is_exit {
+            if depth == k {
+                let l = start.take().expect("region start");
+                covered.union_in_place(Interval::closed(l, point));
+            }
+            depth -= 1;
+        } else {
+            depth += 1;
+            if depth == k {
+                start = Some(point);
+            }
+        }
+    }
+    covered
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Clustering
+////////////////////////////////////////////////////////////////////////////////
+/// Groups the given `Interval`s into clusters, in which each `Interval` lies
+/// within the given distance of the `Interval`s before it. Distances are
+/// measured as [`Interval::distance_to`] does, and empty `Interval`s are
+/// ignored.
+///
+/// The clusters are returned in order, and the members of each cluster are
+/// ordered by their lower bounds.
+///
+/// [`Interval::distance_to`]: ../interval/struct.Interval.html#method.distance_to
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::algorithm::clusters;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let events = vec![
+///     Interval::closed(100, 105),
+///     Interval::closed(0, 10),
+///     Interval::closed(2, 4),
+///     Interval::closed(25, 30),
+///     Interval::closed(40, 41),
+/// ];
+///
+/// let sessions = clusters(events, 15);
+/// assert_eq!(sessions.len(), 2);
+/// assert_eq!(sessions[0].hull, Interval::closed(0, 41));
+/// assert_eq!(sessions[0].members.len(), 4);
+/// assert_eq!(sessions[1].hull, Interval::closed(100, 105));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn clusters<T, D, I>(intervals: I, distance: D) -> Vec<Cluster<T>>
+    where
+        T: IntervalPoint + Sub<Output=D>,
+        RawInterval<T>: Normalize,
+        D: Ord + Default,
+        I: IntoIterator<Item=Interval<T>>,
+{
+    let mut intervals: Vec<_> = intervals
+        .into_iter()
+        .filter(|interval| !interval.is_empty())
+        .collect();
+    intervals.sort_by(|a, b| {
+        let a = LowerBound(a.lower_bound_ref().expect("nonempty interval"));
+        let b = LowerBound(b.lower_bound_ref().expect("nonempty interval"));
+        a.cmp(&b)
+    });
+
+    let mut clusters: Vec<Cluster<T>> = Vec::new();
+    for interval in intervals {
+        match clusters.last_mut() {
+            Some(cluster) if cluster.hull
+                .distance_to(&interval)
+                .is_some_and(|gap: D| gap <= distance) =>
+            {
+                cluster.hull = cluster.hull.enclose(&interval);
+                cluster.members.push(interval);
+            },
+            _ => clusters.push(Cluster {
+                hull: interval.clone(),
+                members: vec![interval],
+            }),
+        }
+    }
+    clusters
+}
+
+/// A group of nearby `Interval`s, as returned by [`clusters`].
+///
+/// [`clusters`]: fn.clusters.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cluster<T> {
+    /// The `Interval`s of the cluster, ordered by their lower bounds.
+    pub members: Vec<Interval<T>>,
+    /// The smallest `Interval` containing all of the members.
+    pub hull: Interval<T>,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Utility functions
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the smallest `Interval` enclosing both of the given `Interval`s if
+/// they overlap or are adjacent, or `None` otherwise.
+pub(in crate) fn coalesce<T>(a: &Interval<T>, b: &Interval<T>) -> Option<Interval<T>>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    // Compare denormalized intervals so that adjacent points are detected.
+    let a = a.0.clone().denormalized();
+    let b = b.0.clone().denormalized();
+    if a.intersects(&b) || a.adjacent(&b) {
+        Some(a.enclose(&b).normalized().into())
+    } else {
+        None
+    }
+}
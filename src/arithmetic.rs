@@ -0,0 +1,302 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides interval arithmetic over totally ordered floats.
+//!
+//! Operations treat their operands as sets of extended real numbers. NaN
+//! points, which are ordered outside of the infinities, are ignored, and
+//! results are rounded outward by one unit in the last place so that they
+//! always enclose the exact result.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::float::TotalF64;
+use crate::interval::Interval;
+use crate::normalize::Bisect;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Arithmetic operations
+////////////////////////////////////////////////////////////////////////////////
+impl Interval<TotalF64> {
+    /// Returns the midpoint of the `Interval`, or `None` if it is empty or
+    /// has an infinite or NaN bound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::float::TotalF64;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x = Interval::closed(TotalF64(1.0), TotalF64(2.0));
+    /// assert_eq!(x.midpoint(), Some(TotalF64(1.5)));
+    ///
+    /// let y = Interval::closed(TotalF64(1.0), TotalF64(f64::INFINITY));
+    /// assert_eq!(y.midpoint(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn midpoint(&self) -> Option<TotalF64> {
+        let (a, b) = (self.infimum()?.get(), self.supremum()?.get());
+        if !a.is_finite() || !b.is_finite() {
+            return None;
+        }
+        // Halve before adding to avoid overflow.
+        Some(TotalF64(a / 2.0 + b / 2.0))
+    }
+
+    /// Returns the `Interval` of differences of the points of the `Interval`
+    /// and the points of the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::float::TotalF64;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x = Interval::closed(TotalF64(1.0), TotalF64(2.0));
+    /// let y = Interval::closed(TotalF64(0.5), TotalF64(1.0));
+    ///
+    /// let d = x.sub(&y);
+    /// assert!(d.contains(&TotalF64(0.0)));
+    /// assert!(d.contains(&TotalF64(1.5)));
+    /// assert!(!d.contains(&TotalF64(1.6)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sub(&self, other: &Self) -> Self {
+        match (real_bounds(self), real_bounds(other)) {
+            (Some((a, b)), Some((c, d))) => hull(&[a - d, b - c]),
+            _                            => Interval::empty(),
+        }
+    }
+
+    /// Returns the `Selection` of quotients of the points of the `Interval`
+    /// divided by the points of the given divisor, using extended interval
+    /// division.
+    ///
+    /// If the divisor contains zero but the dividend does not, the result is
+    /// split into two unbounded pieces, or one if zero is an endpoint of the
+    /// divisor. If both contain zero, the result is the whole extended real
+    /// line. Dividing by exactly zero gives an empty result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::float::TotalF64;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x = Interval::closed(TotalF64(1.0), TotalF64(2.0));
+    /// let y = Interval::closed(TotalF64(-1.0), TotalF64(4.0));
+    ///
+    /// let q = x.div_extended(&y);
+    /// assert_eq!(q.interval_count(), 2);
+    /// assert!(q.contains(&TotalF64(-1.0)));
+    /// assert!(!q.contains(&TotalF64(0.0)));
+    /// assert!(q.contains(&TotalF64(0.25)));
+    /// assert!(q.contains(&TotalF64(f64::INFINITY)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn div_extended(&self, divisor: &Self) -> Selection<TotalF64> {
+        let ((a, b), (c, d)) = match (real_bounds(self), real_bounds(divisor)) {
+            (Some(x), Some(y)) => (x, y),
+            _                  => return Selection::new(),
+        };
+
+        if !(c <= 0.0 && 0.0 <= d) {
+            let quotients = [a / c, a / d, b / c, b / d];
+            return Selection::from(hull(&quotients));
+        }
+        if a <= 0.0 && 0.0 <= b {
+            return Selection::from(real_line());
+        }
+        if c == 0.0 && d == 0.0 {
+            return Selection::new();
+        }
+
+        // The dividend is strictly positive or strictly negative here, so the
+        // quotient tends toward the infinities as the divisor approaches zero.
+        let (neg, pos) = if a > 0.0 {
+            (if c < 0.0 { Some(a / c) } else { None },
+             if d > 0.0 { Some(a / d) } else { None })
+        } else {
+            (if d > 0.0 { Some(b / d) } else { None },
+             if c < 0.0 { Some(b / c) } else { None })
+        };
+
+        let mut quotient = Selection::new();
+        if let Some(upper) = neg {
+            quotient.union_in_place(outward(f64::NEG_INFINITY, upper));
+        }
+        if let Some(lower) = pos {
+            quotient.union_in_place(outward(lower, f64::INFINITY));
+        }
+        quotient
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Root finding
+////////////////////////////////////////////////////////////////////////////////
+/// Performs one interval Newton step, returning the points of `x` which may
+/// contain a root of a function.
+///
+/// `f_mid` must enclose the function's value at the [`midpoint`] of `x`, and
+/// `f_deriv` must enclose its derivative over all of `x`. The step computes
+/// `x ∩ (m - f_mid / f_deriv)` using [`div_extended`], so the result may be
+/// split in two when the derivative enclosure contains zero. Every root in
+/// `x` is kept, and an empty result proves that `x` contains no root. If `x`
+/// has no midpoint, it is returned unchanged.
+///
+/// [`midpoint`]: ../interval/struct.Interval.html#method.midpoint
+/// [`div_extended`]: ../interval/struct.Interval.html#method.div_extended
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::arithmetic::newton_step;
+/// # use normalize_interval::float::TotalF64;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// // Enclose the root of f(x) = x² - 2 in [1, 2].
+/// let mut x = Interval::closed(TotalF64(1.0), TotalF64(2.0));
+/// for _ in 0..6 {
+///     let m = x.midpoint().unwrap().get();
+///     let f_mid = Interval::point(TotalF64(m * m - 2.0));
+///     let (a, b) = (x.infimum().unwrap().get(), x.supremum().unwrap().get());
+///     let f_deriv = Interval::closed(TotalF64(2.0 * a), TotalF64(2.0 * b));
+///
+///     x = newton_step(&f_mid, &f_deriv, &x).enclose();
+/// }
+///
+/// assert!(x.contains(&TotalF64(2f64.sqrt())));
+/// assert!(x.supremum().unwrap().get() - x.infimum().unwrap().get() < 1e-12);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn newton_step(
+    f_mid: &Interval<TotalF64>,
+    f_deriv: &Interval<TotalF64>,
+    x: &Interval<TotalF64>)
+    -> Selection<TotalF64>
+{
+    let m = match x.midpoint() {
+        Some(m) => Interval::point(m),
+        None    => return Selection::from(*x),
+    };
+
+    let mut step = Selection::new();
+    for quotient in f_mid.div_extended(f_deriv).interval_iter() {
+        step.union_in_place(m.sub(&quotient));
+    }
+    step.intersect_in_place(*x);
+    step
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bisection
+////////////////////////////////////////////////////////////////////////////////
+/// Bisects `TotalF64` intervals at their [`midpoint`]. If the midpoint is
+/// undefined because a bound is infinite or NaN, or if it rounds to the upper
+/// point, the points are split at the middle of their total order instead.
+///
+/// [`midpoint`]: ../interval/struct.Interval.html#method.midpoint
+impl Bisect for TotalF64 {
+    fn bisector(lower: &Self, upper: &Self) -> Self {
+        match Interval::closed(*lower, *upper).midpoint() {
+            Some(mid) if *lower <= mid && mid < *upper => mid,
+            _ => {
+                let (a, b) = (i128::from(lower.key()), i128::from(upper.key()));
+                // The middle key lies between two `i64` keys, so it fits.
+                TotalF64::from_key((a + (b - a) / 2) as i64)
+            },
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Helper functions
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the `Interval` of all non-NaN points.
+fn real_line() -> Interval<TotalF64> {
+    Interval::closed(TotalF64(f64::NEG_INFINITY), TotalF64(f64::INFINITY))
+}
+
+/// Returns the least and greatest non-NaN points of the given `Interval`.
+fn real_bounds(interval: &Interval<TotalF64>) -> Option<(f64, f64)> {
+    let real = interval.intersect(&real_line());
+    match (real.infimum(), real.supremum()) {
+        (Some(l), Some(u)) => Some((l.get(), u.get())),
+        _                  => None,
+    }
+}
+
+/// Returns the `Interval` between the given points, rounded outward.
+fn outward(lower: f64, upper: f64) -> Interval<TotalF64> {
+    Interval::closed(TotalF64(next_down(lower)), TotalF64(next_up(upper)))
+}
+
+/// Returns the least float greater than the given value. Positive infinity and
+/// NaN are returned unchanged.
+pub(in crate) fn next_up(value: f64) -> f64 {
+    if value.is_nan() || value == f64::INFINITY {
+        return value;
+    }
+    let bits = value.to_bits();
+    let magnitude = bits & !(1 << 63);
+    let next = if magnitude == 0 {
+        // Both zeros step to the least positive subnormal.
+        1
+    } else if bits == magnitude {
+        bits + 1
+    } else {
+        bits - 1
+    };
+    f64::from_bits(next)
+}
+
+/// Returns the greatest float less than the given value. Negative infinity and
+/// NaN are returned unchanged.
+pub(in crate) fn next_down(value: f64) -> f64 {
+    -next_up(-value)
+}
+
+/// Returns the outward rounded hull of the given values, ignoring NaNs. If
+/// every value is NaN, the whole extended real line is returned.
+fn hull(values: &[f64]) -> Interval<TotalF64> {
+    let mut bounds: Option<(f64, f64)> = None;
+    for &v in values.iter().filter(|v| !v.is_nan()) {
+        bounds = Some(match bounds {
+            Some((l, u)) => (l.min(v), u.max(v)),
+            None         => (v, v),
+        });
+    }
+    match bounds {
+        Some((l, u)) => outward(l, u),
+        None         => real_line(),
+    }
+}
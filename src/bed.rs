@@ -0,0 +1,218 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides reading and writing of named intervals in tab-delimited,
+//! BED-style text.
+//!
+//! Each record is a line holding a name, a start offset, and an end offset,
+//! separated by tabs. Following the BED convention, the start offset is
+//! included and the end offset is excluded. Any further columns are ignored
+//! when reading. Blank lines, and lines beginning with `#`, `track`, or
+//! `browser` are skipped.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+
+// Standard library imports.
+use std::fmt::Display;
+use std::io::BufRead;
+use std::io::Write;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Reading
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator over the `(name, Interval)` records read from the given
+/// BED-style text.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::bed::read_bed;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let text = "# comment\nchr1\t100\t200\tgene_a\nchr1\t150\t300\n";
+///
+/// let sel: Selection<u64> = read_bed(text.as_bytes())
+///     .map(|record| record.map(|(_name, interval)| interval))
+///     .collect::<Result<_, _>>()?;
+///
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(100, 299),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn read_bed<R>(reader: R) -> BedRecords<R> where R: BufRead {
+    BedRecords {
+        reader,
+        line: String::new(),
+        line_number: 0,
+    }
+}
+
+/// An iterator over the records of BED-style text. This `struct` is created
+/// by the [`read_bed`] function.
+///
+/// [`read_bed`]: fn.read_bed.html
+#[derive(Debug)]
+pub struct BedRecords<R> {
+    /// The reader providing the text.
+    reader: R,
+    /// The buffer holding the current line.
+    line: String,
+    /// The number of the current line, starting from 1.
+    line_number: usize,
+}
+
+impl<R> Iterator for BedRecords<R> where R: BufRead {
+    type Item = Result<(String, Interval<u64>), BedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0)  => return None,
+                Ok(_)  => self.line_number += 1,
+                Err(e) => return Some(Err(BedError::Io(e))),
+            }
+
+            let line = self.line.trim_end_matches(&['\n', '\r'][..]);
+            if line.trim().is_empty()
+                || line.starts_with('#')
+                || line.starts_with("track")
+                || line.starts_with("browser")
+            {
+                continue;
+            }
+            return Some(parse_record(line)
+                .ok_or(BedError::InvalidRecord(self.line_number)));
+        }
+    }
+}
+
+/// Parses a single BED-style record.
+fn parse_record(line: &str) -> Option<(String, Interval<u64>)> {
+    let mut fields = line.split('\t');
+    let name = fields.next()?;
+    let start = fields.next()?.trim().parse::<u64>().ok()?;
+    let end = fields.next()?.trim().parse::<u64>().ok()?;
+    if start > end {
+        return None;
+    }
+    Some((name.to_owned(), Interval::right_open(start, end)))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Writing
+////////////////////////////////////////////////////////////////////////////////
+/// Writes the given `(name, Interval)` records as BED-style text.
+///
+/// # Errors
+///
+/// Returns a [`BedError`] if writing fails, if an `Interval` is empty and so
+/// has no offsets to write, or if an `Interval` contains `u64::MAX`, which
+/// cannot be given as an excluded end offset. Records before the failing one
+/// will already have been written.
+///
+/// [`BedError`]: enum.BedError.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::bed::write_bed;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut out = Vec::new();
+/// write_bed(&mut out, vec![
+///     ("chr1", Interval::closed(100, 199)),
+///     ("chr2", Interval::point(7)),
+/// ])?;
+///
+/// assert_eq!(String::from_utf8(out)?, "chr1\t100\t200\nchr2\t7\t8\n");
+///
+/// let mut out = Vec::new();
+/// assert!(write_bed(&mut out, vec![("chr3", Interval::empty())]).is_err());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn write_bed<W, N, I>(mut writer: W, records: I) -> Result<(), BedError>
+    where
+        W: Write,
+        N: Display,
+        I: IntoIterator<Item=(N, Interval<u64>)>,
+{
+    for (name, interval) in records {
+        let (start, last) = match (interval.infimum(), interval.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Err(BedError::EmptyInterval),
+        };
+        let end = last.checked_add(1).ok_or(BedError::UnrepresentableEnd)?;
+        writeln!(writer, "{}\t{}\t{}", name, start, end)
+            .map_err(BedError::Io)?;
+    }
+    Ok(())
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BedError
+////////////////////////////////////////////////////////////////////////////////
+/// An error which can be returned when reading or writing BED-style text.
+#[derive(Debug)]
+pub enum BedError {
+    /// An I/O error occurred.
+    Io(std::io::Error),
+    /// The record on the given line was malformed.
+    InvalidRecord(usize),
+    /// An empty `Interval` was given to write, which has no offsets.
+    EmptyInterval,
+    /// An `Interval` ended at `u64::MAX`, which has no excluded end offset.
+    UnrepresentableEnd,
+}
+
+impl Display for BedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            BedError::Io(ref e)
+                => write!(f, "I/O error: {}", e),
+            BedError::InvalidRecord(line)
+                => write!(f, "invalid record on line {}", line),
+            BedError::EmptyInterval
+                => write!(f, "empty interval has no offsets to write"),
+            BedError::UnrepresentableEnd
+                => write!(f, "interval end offset is not representable"),
+        }
+    }
+}
+
+impl std::error::Error for BedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            BedError::Io(ref e) => Some(e),
+            _                   => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for BedError {
+    fn from(e: std::io::Error) -> Self {
+        BedError::Io(e)
+    }
+}
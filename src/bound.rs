@@ -13,6 +13,7 @@
 
 // Standard library imports.
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::default::Default;
 
 // Local enum shortcut.
@@ -49,7 +50,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<i32> = Bound::Include(15);
@@ -77,7 +78,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<i32> = Bound::Include(15);
@@ -105,7 +106,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<i32> = Bound::Exclude(15);
@@ -137,7 +138,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<i32> = Bound::Exclude(34);
@@ -166,7 +167,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let mut x: Bound<i32> = Bound::Exclude(34);
@@ -209,7 +210,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<i32> = Bound::Exclude(34);
@@ -219,9 +220,9 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     /// # }
     /// ```
     ///
-    /// ```rust{.should_panic}
+    /// ```rust,should_panic
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<i32> = Bound::Infinite;
@@ -246,7 +247,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// assert_eq!(Bound::Exclude(34).unwrap_or(15), 34);
@@ -270,7 +271,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let k = 10;
@@ -299,7 +300,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<u32> = Bound::Include(10);
@@ -328,7 +329,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// assert_eq!(Bound::Include(10).map_or(6, |k| k * 2), 20);
@@ -354,7 +355,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// assert_eq!(Bound::Include(10).map_or_else(|| 6, |k| k * 2), 20);
@@ -385,7 +386,7 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound;
+    /// # use normalize_interval::Bound;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let x: Bound<i32> = Bound::transfer(Bound::Exclude(34), 18);
@@ -532,3 +533,137 @@ impl<T> From<T> for Bound<T> {
         Include(t)
     }
 }
+
+
+////////////////////////////////////////////////////////////////////////////////
+// LowerBound
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Bound`] used as the lower boundary of an interval.
+///
+/// `LowerBound`s are ordered by the points they admit: an [`Infinite`] bound
+/// is less than any finite bound, and an [`Include`] bound is less than an
+/// [`Exclude`] bound at the same point.
+///
+/// [`Bound`]: enum.Bound.html
+/// [`Include`]: enum.Bound.html#variant.Include
+/// [`Exclude`]: enum.Bound.html#variant.Exclude
+/// [`Infinite`]: enum.Bound.html#variant.Infinite
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Bound;
+/// # use normalize_interval::bound::LowerBound;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert!(LowerBound(Bound::Include(3)) < LowerBound(Bound::Exclude(3)));
+/// assert!(LowerBound(Bound::Infinite) < LowerBound(Bound::Include(-100)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LowerBound<T>(pub Bound<T>);
+
+impl<T> LowerBound<T> {
+    /// Returns the inner `Bound`.
+    #[inline]
+    pub fn into_inner(self) -> Bound<T> {
+        self.0
+    }
+}
+
+impl<T> From<Bound<T>> for LowerBound<T> {
+    #[inline]
+    fn from(bound: Bound<T>) -> Self {
+        LowerBound(bound)
+    }
+}
+
+impl<T> PartialOrd for LowerBound<T> where T: Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for LowerBound<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Infinite,   Infinite)   => Ordering::Equal,
+            (Infinite,   _)          => Ordering::Less,
+            (_,          Infinite)   => Ordering::Greater,
+            (Include(p), Include(o)) |
+            (Exclude(p), Exclude(o)) => p.cmp(o),
+            (Include(p), Exclude(o)) => p.cmp(o).then(Ordering::Less),
+            (Exclude(p), Include(o)) => p.cmp(o).then(Ordering::Greater),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// UpperBound
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Bound`] used as the upper boundary of an interval.
+///
+/// `UpperBound`s are ordered by the points they admit: an [`Infinite`] bound
+/// is greater than any finite bound, and an [`Exclude`] bound is less than an
+/// [`Include`] bound at the same point.
+///
+/// [`Bound`]: enum.Bound.html
+/// [`Include`]: enum.Bound.html#variant.Include
+/// [`Exclude`]: enum.Bound.html#variant.Exclude
+/// [`Infinite`]: enum.Bound.html#variant.Infinite
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Bound;
+/// # use normalize_interval::bound::UpperBound;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert!(UpperBound(Bound::Exclude(3)) < UpperBound(Bound::Include(3)));
+/// assert!(UpperBound(Bound::Infinite) > UpperBound(Bound::Include(100)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UpperBound<T>(pub Bound<T>);
+
+impl<T> UpperBound<T> {
+    /// Returns the inner `Bound`.
+    #[inline]
+    pub fn into_inner(self) -> Bound<T> {
+        self.0
+    }
+}
+
+impl<T> From<Bound<T>> for UpperBound<T> {
+    #[inline]
+    fn from(bound: Bound<T>) -> Self {
+        UpperBound(bound)
+    }
+}
+
+impl<T> PartialOrd for UpperBound<T> where T: Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for UpperBound<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Infinite,   Infinite)   => Ordering::Equal,
+            (Infinite,   _)          => Ordering::Greater,
+            (_,          Infinite)   => Ordering::Less,
+            (Include(p), Include(o)) |
+            (Exclude(p), Exclude(o)) => p.cmp(o),
+            (Include(p), Exclude(o)) => p.cmp(o).then(Ordering::Greater),
+            (Exclude(p), Include(o)) => p.cmp(o).then(Ordering::Less),
+        }
+    }
+}
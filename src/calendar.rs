@@ -0,0 +1,105 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides splitting of timestamp intervals along local calendar boundaries.
+//! Requires the `chrono` feature.
+//!
+//! Timestamps are given as `i64` seconds since the Unix epoch, so that their
+//! intervals are normalized like any other integer interval.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+
+// External library imports.
+use chrono::LocalResult;
+use chrono::NaiveDate;
+use chrono::TimeZone;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Calendar splitting
+////////////////////////////////////////////////////////////////////////////////
+impl Interval<i64> {
+    /// Splits the `Interval` of Unix timestamps into subintervals covering
+    /// each local calendar day in the given time zone.
+    ///
+    /// Each day begins at the first instant of its date in the time zone. If
+    /// local midnight is skipped by a daylight saving transition, the day
+    /// begins at the first local time after the gap. If local midnight is
+    /// repeated, the day begins at its earlier occurrence. Days may therefore
+    /// be shorter or longer than 86400 seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use chrono::FixedOffset;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// // 1970-01-01T20:00 to 1970-01-03T02:00 in UTC-02:00.
+    /// let tz = FixedOffset::west_opt(2 * 3600).unwrap();
+    /// let session: Interval<i64> = Interval::closed(22 * 3600, 52 * 3600);
+    ///
+    /// assert_eq!(session.split_by_day(&tz), [
+    ///     Interval::closed(22 * 3600, 26 * 3600 - 1),
+    ///     Interval::closed(26 * 3600, 50 * 3600 - 1),
+    ///     Interval::closed(50 * 3600, 52 * 3600),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_by_day<Tz>(&self, tz: &Tz) -> Vec<Self> where Tz: TimeZone {
+        let (lower, upper) = match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Vec::new(),
+        };
+
+        let mut parts = Vec::new();
+        let mut start = lower;
+        loop {
+            let next = match tz.timestamp_opt(start, 0) {
+                LocalResult::Single(t) => t.date_naive()
+                    .succ_opt()
+                    .and_then(|date| day_start(tz, date)),
+                _                      => None,
+            };
+            match next {
+                Some(next) if next <= upper => {
+                    parts.push(Interval::closed(start, next - 1));
+                    start = next;
+                },
+                _ => {
+                    parts.push(Interval::closed(start, upper));
+                    return parts;
+                },
+            }
+        }
+    }
+}
+
+/// Returns the Unix timestamp of the first instant of the given local date in
+/// the given time zone.
+fn day_start<Tz>(tz: &Tz, date: NaiveDate) -> Option<i64> where Tz: TimeZone {
+    let midnight = date.and_hms_opt(0, 0, 0)?;
+    // Daylight saving gaps are at most a day long, so scan forward by the
+    // minute until a local time exists.
+    for minute in 0..(24 * 60) {
+        let local = midnight + chrono::Duration::minutes(minute);
+        match tz.from_local_datetime(&local) {
+            LocalResult::Single(t)       => return Some(t.timestamp()),
+            LocalResult::Ambiguous(t, _) => return Some(t.timestamp()),
+            LocalResult::None            => continue,
+        }
+    }
+    None
+}
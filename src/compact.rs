@@ -0,0 +1,524 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a compact, read-optimized storage for large selections.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::IntervalPoint;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::iter::FromIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CompactSelection
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Selection`] stored as two flat, sorted arrays of bound points.
+///
+/// `CompactSelection` trades the cheap mutation of a [`Selection`] for dense
+/// storage and cache-friendly binary search queries, which suits large
+/// selections which are built once and queried often. Because normalized
+/// `Interval`s always include their bound points, no flags are needed to track
+/// the openness of each bound.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompactSelection<T> {
+    /// The lower bound points of each `Interval`, in ascending order.
+    lowers: Vec<T>,
+    /// The upper bound points of each `Interval`, in ascending order.
+    uppers: Vec<T>,
+}
+
+impl<T> CompactSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    ////////////////////////////////////////////////////////////////////////////
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `CompactSelection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::compact::CompactSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: CompactSelection<i32> = CompactSelection::new();
+    /// assert!(sel.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        CompactSelection {
+            lowers: Vec::new(),
+            uppers: Vec::new(),
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `CompactSelection` contains no points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::compact::CompactSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: CompactSelection<i32> = Interval::closed(0, 4).into();
+    /// assert!(!sel.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.lowers.is_empty()
+    }
+
+    /// Returns the number of disjoint `Interval`s in the `CompactSelection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::compact::CompactSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: CompactSelection<i32> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(3, 6),
+    ///     Interval::closed(9, 12),
+    /// ].into_iter().collect();
+    /// assert_eq!(sel.interval_count(), 2);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn interval_count(&self) -> usize {
+        self.lowers.len()
+    }
+
+    /// Returns `true` if the `CompactSelection` contains the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::compact::CompactSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: CompactSelection<i32> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(9, 12),
+    /// ].into_iter().collect();
+    ///
+    /// assert!(sel.contains(&3));
+    /// assert!(!sel.contains(&6));
+    /// assert!(sel.contains(&12));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn contains(&self, point: &T) -> bool {
+        let idx = self.uppers.partition_point(|upper| upper < point);
+        idx < self.lowers.len() && self.lowers[idx] <= *point
+    }
+
+    /// Returns `true` if the `CompactSelection` shares any points with the
+    /// given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::compact::CompactSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: CompactSelection<i32> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(9, 12),
+    /// ].into_iter().collect();
+    ///
+    /// assert!(sel.intersects(&Interval::closed(4, 7)));
+    /// assert!(!sel.intersects(&Interval::closed(5, 8)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersects(&self, interval: &Interval<T>) -> bool {
+        let (l, u) = match (interval.infimum(), interval.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return false,
+        };
+        let idx = self.uppers.partition_point(|upper| *upper < l);
+        idx < self.lowers.len() && self.lowers[idx] <= u
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Iteration
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the disjoint `Interval`s of the
+    /// `CompactSelection` in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::compact::CompactSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: CompactSelection<i32> = vec![
+    ///     Interval::closed(9, 12),
+    ///     Interval::closed(0, 4),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(9, 12),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interval_iter(&self)
+        -> impl DoubleEndedIterator<Item=Interval<T>> + '_
+    {
+        self.lowers.iter()
+            .zip(self.uppers.iter())
+            .map(|(l, u)| Interval::closed(l.clone(), u.clone()))
+    }
+
+    /// Converts the `CompactSelection` into a `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::compact::CompactSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    /// let compact = CompactSelection::from(&sel);
+    ///
+    /// assert_eq!(compact.to_selection(), sel);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_selection(&self) -> Selection<T> {
+        self.interval_iter().collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Default
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Default for CompactSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        CompactSelection::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+impl<'t, T> From<&'t Selection<T>> for CompactSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(selection: &'t Selection<T>) -> Self {
+        let count = selection.interval_count();
+        let mut lowers = Vec::with_capacity(count);
+        let mut uppers = Vec::with_capacity(count);
+        for (l, u) in selection.endpoints() {
+            lowers.push(bound_point(l));
+            uppers.push(bound_point(u));
+        }
+        CompactSelection { lowers, uppers }
+    }
+}
+
+impl<T> From<Selection<T>> for CompactSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(selection: Selection<T>) -> Self {
+        CompactSelection::from(&selection)
+    }
+}
+
+impl<T> From<Interval<T>> for CompactSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(interval: Interval<T>) -> Self {
+        CompactSelection::from(Selection::from(interval))
+    }
+}
+
+impl<T> From<CompactSelection<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(compact: CompactSelection<T>) -> Self {
+        compact.to_selection()
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for CompactSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item=Interval<T>>
+    {
+        CompactSelection::from(iter.into_iter().collect::<Selection<T>>())
+    }
+}
+
+/// Returns the point of a bound of a normalized `Interval`.
+fn bound_point<T>(bound: Bound<T>) -> T {
+    match bound {
+        Bound::Include(p) => p,
+        _                 => unreachable!("normalized bounds include points"),
+    }
+}
+
+/// Returns the least and greatest points of the given `Selection`, or `None`
+/// if it is empty.
+fn span(selection: &Selection<u32>) -> Option<(u32, u32)> {
+    match (selection.lower_bound(), selection.upper_bound()) {
+        (Some(l), Some(u)) => Some((bound_point(l), bound_point(u))),
+        _                  => None,
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// DenseSelection
+////////////////////////////////////////////////////////////////////////////////
+/// A `Selection<u32>` stored as a bitset over the span of its points.
+///
+/// `DenseSelection` uses one bit per point between the least and greatest
+/// points of the `Selection`, so membership tests are a single bit test. This
+/// suits selections over small, densely populated domains, such as ranges of
+/// ids; a sparse selection over a wide span will use a great deal of memory.
+///
+/// # Memory
+///
+/// Converting with `From` allocates one bit for every point in the span, up to
+/// 512 MiB for a selection spanning all of `u32`, and aborts if the allocation
+/// fails. Use [`with_max_span`] to refuse selections spanning more points than
+/// a given limit.
+///
+/// [`with_max_span`]: #method.with_max_span
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::compact::DenseSelection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: Selection<u32> = Selection::from(Interval::closed(100, 163));
+/// sel.union_in_place(Interval::closed(200, 210));
+///
+/// let dense = DenseSelection::from(&sel);
+/// assert!(dense.contains(&130));
+/// assert!(!dense.contains(&180));
+/// assert_eq!(dense.point_count(), 75);
+/// assert_eq!(dense.to_selection(), sel);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DenseSelection {
+    /// The point corresponding to the first bit.
+    start: u32,
+    /// The number of bits spanned by the selection.
+    len: u64,
+    /// The bits of the selection, least significant bit first.
+    words: Vec<u64>,
+}
+
+impl DenseSelection {
+    /// Converts the given `Selection` into a `DenseSelection`, or returns
+    /// `None` if the `Selection` spans more than `max_span` points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::compact::DenseSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u32> = Selection::from(Interval::closed(0, 9));
+    /// assert!(DenseSelection::with_max_span(&sel, 10).is_some());
+    ///
+    /// sel.union_in_place(Interval::point(u32::MAX));
+    /// assert!(DenseSelection::with_max_span(&sel, 1 << 20).is_none());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_span(selection: &Selection<u32>, max_span: u64)
+        -> Option<Self>
+    {
+        match span(selection) {
+            Some((l, u)) if u64::from(u - l) >= max_span => None,
+            _ => Some(DenseSelection::from(selection)),
+        }
+    }
+
+    /// Returns `true` if the `DenseSelection` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of points in the `DenseSelection`.
+    pub fn point_count(&self) -> u64 {
+        self.words.iter().map(|w| u64::from(w.count_ones())).sum()
+    }
+
+    /// Returns `true` if the `DenseSelection` contains the given point.
+    #[inline]
+    pub fn contains(&self, point: &u32) -> bool {
+        if *point < self.start {
+            return false;
+        }
+        let idx = u64::from(*point - self.start);
+        idx < self.len
+            && self.words[(idx / 64) as usize] & (1 << (idx % 64)) != 0
+    }
+
+    /// Converts the `DenseSelection` into a `Selection`.
+    pub fn to_selection(&self) -> Selection<u32> {
+        let mut selection = Selection::new();
+        let mut run_start: Option<u64> = None;
+        for (w, &word) in self.words.iter().enumerate() {
+            match (word, run_start) {
+                (0, None) | (u64::MAX, Some(_)) => continue,
+                _                                    => (),
+            }
+            for b in 0..64 {
+                let idx = w as u64 * 64 + b;
+                match (word & (1 << b) != 0, run_start) {
+                    (true, None)     => run_start = Some(idx),
+                    (false, Some(s)) => {
+                        selection.union_in_place(self.interval(s, idx - 1));
+                        run_start = None;
+                    },
+                    _                => (),
+                }
+            }
+        }
+        if let Some(s) = run_start {
+            selection.union_in_place(self.interval(s, self.len - 1));
+        }
+        selection
+    }
+
+    /// Returns the `Interval` of points between the given bit indices.
+    fn interval(&self, lower: u64, upper: u64) -> Interval<u32> {
+        Interval::closed(
+            self.start + lower as u32,
+            self.start + upper as u32)
+    }
+
+    /// Sets the bits between the given indices, inclusive.
+    fn set_range(&mut self, lower: u64, upper: u64) {
+        let (lw, uw) = ((lower / 64) as usize, (upper / 64) as usize);
+        let low_mask = u64::MAX << (lower % 64);
+        let high_mask = u64::MAX >> (63 - upper % 64);
+        if lw == uw {
+            self.words[lw] |= low_mask & high_mask;
+            return;
+        }
+        self.words[lw] |= low_mask;
+        for word in &mut self.words[lw + 1..uw] {
+            *word = u64::MAX;
+        }
+        self.words[uw] |= high_mask;
+    }
+}
+
+impl<'t> From<&'t Selection<u32>> for DenseSelection {
+    fn from(selection: &'t Selection<u32>) -> Self {
+        let (start, end) = match span(selection) {
+            Some(span) => span,
+            None       => return DenseSelection::default(),
+        };
+        let len = u64::from(end - start) + 1;
+        let mut dense = DenseSelection {
+            start,
+            len,
+            words: vec![0; len.div_ceil(64) as usize],
+        };
+        for (l, u) in selection.endpoints() {
+            dense.set_range(
+                u64::from(bound_point(l) - start),
+                u64::from(bound_point(u) - start));
+        }
+        dense
+    }
+}
+
+impl From<Selection<u32>> for DenseSelection {
+    fn from(selection: Selection<u32>) -> Self {
+        DenseSelection::from(&selection)
+    }
+}
+
+impl From<DenseSelection> for Selection<u32> {
+    fn from(dense: DenseSelection) -> Self {
+        dense.to_selection()
+    }
+}
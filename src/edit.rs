@@ -0,0 +1,247 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides operations for remapping offset intervals across edits to a
+//! sequence, such as a text buffer.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// InsertPolicy
+////////////////////////////////////////////////////////////////////////////////
+/// Determines how an `Interval` is remapped when items are inserted strictly
+/// inside of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InsertPolicy {
+    /// Grow the `Interval` to cover the inserted items.
+    Expand,
+    /// Split the `Interval` around the inserted items.
+    Split,
+    /// Remove the `Interval`.
+    Drop,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval remapping
+////////////////////////////////////////////////////////////////////////////////
+impl Interval<usize> {
+    /// Returns the `Interval` of offsets remapped after inserting the given
+    /// number of items at the given offset. Offsets at or after the insertion
+    /// point are shifted up, and an `Interval` containing the insertion point
+    /// after its first offset is handled according to the given
+    /// [`InsertPolicy`].
+    ///
+    /// [`InsertPolicy`]: ../edit/enum.InsertPolicy.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if a remapped offset overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::edit::InsertPolicy;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let span: Interval<usize> = Interval::closed(10, 19);
+    ///
+    /// let expanded = span.remap_insert(15, 3, InsertPolicy::Expand);
+    /// assert_eq!(expanded.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(10, 22),
+    /// ]);
+    ///
+    /// let split = span.remap_insert(15, 3, InsertPolicy::Split);
+    /// assert_eq!(split.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(10, 14),
+    ///     Interval::closed(18, 22),
+    /// ]);
+    ///
+    /// let dropped = span.remap_insert(15, 3, InsertPolicy::Drop);
+    /// assert!(dropped.is_empty());
+    ///
+    /// let shifted = span.remap_insert(10, 3, InsertPolicy::Split);
+    /// assert_eq!(shifted.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(13, 22),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remap_insert(&self, at: usize, len: usize, policy: InsertPolicy)
+        -> Selection<usize>
+    {
+        let (l, u) = match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Selection::new(),
+        };
+
+        if u < at {
+            return Selection::from(*self);
+        }
+        let shift = |offset: usize| offset
+            .checked_add(len)
+            .expect("remapped offset overflow");
+        if l >= at || len == 0 {
+            return Selection::from(Interval::closed(shift(l), shift(u)));
+        }
+        match policy {
+            InsertPolicy::Expand => Selection::from(
+                Interval::closed(l, shift(u))),
+            InsertPolicy::Split  => vec![
+                Interval::closed(l, at - 1),
+                Interval::closed(shift(at), shift(u)),
+            ].into_iter().collect(),
+            InsertPolicy::Drop   => Selection::new(),
+        }
+    }
+
+    /// Returns the `Interval` of offsets remapped after deleting the given
+    /// `Interval` of offsets. Deleted offsets are removed, and offsets after
+    /// the deletion are shifted down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let span: Interval<usize> = Interval::closed(10, 19);
+    ///
+    /// assert_eq!(span.remap_delete(&Interval::closed(15, 24)),
+    ///     Interval::closed(10, 14));
+    /// assert_eq!(span.remap_delete(&Interval::closed(0, 4)),
+    ///     Interval::closed(5, 14));
+    /// assert_eq!(span.remap_delete(&Interval::closed(8, 21)),
+    ///     Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remap_delete(&self, deleted: &Interval<usize>) -> Self {
+        let (l, u) = match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Interval::empty(),
+        };
+        let (a, b) = match (deleted.infimum(), deleted.supremum()) {
+            (Some(a), Some(b)) => (a, b),
+            _                  => return *self,
+        };
+        let removed = b - a + 1;
+
+        let lower = if l < a { l } else if l > b { l - removed } else { a };
+        let upper = if u < a {
+            u
+        } else if u > b {
+            u - removed
+        } else {
+            match a.checked_sub(1) {
+                Some(upper) => upper,
+                None        => return Interval::empty(),
+            }
+        };
+        if lower > upper {
+            return Interval::empty();
+        }
+        Interval::closed(lower, upper)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection remapping
+////////////////////////////////////////////////////////////////////////////////
+impl Selection<usize> {
+    /// Returns the `Selection` of offsets remapped after inserting the given
+    /// number of items at the given offset, remapping each of its `Interval`s
+    /// as [`Interval::remap_insert`] does.
+    ///
+    /// [`Interval::remap_insert`]: ../interval/struct.Interval.html#method.remap_insert
+    ///
+    /// # Panics
+    ///
+    /// Panics if a remapped offset overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::edit::InsertPolicy;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<usize> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 14),
+    /// ].into_iter().collect();
+    ///
+    /// let sel = sel.remap_insert(2, 6, InsertPolicy::Split);
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 1),
+    ///     Interval::closed(8, 10),
+    ///     Interval::closed(16, 20),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remap_insert(&self, at: usize, len: usize, policy: InsertPolicy)
+        -> Self
+    {
+        let mut remapped = Selection::new();
+        for interval in self.interval_iter() {
+            for part in interval.remap_insert(at, len, policy).interval_iter() {
+                remapped.union_in_place(part);
+            }
+        }
+        remapped
+    }
+
+    /// Returns the `Selection` of offsets remapped after deleting the given
+    /// `Interval` of offsets, remapping each of its `Interval`s as
+    /// [`Interval::remap_delete`] does.
+    ///
+    /// [`Interval::remap_delete`]: ../interval/struct.Interval.html#method.remap_delete
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<usize> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 14),
+    /// ].into_iter().collect();
+    ///
+    /// let sel = sel.remap_delete(&Interval::closed(3, 11));
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 5),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remap_delete(&self, deleted: &Interval<usize>) -> Self {
+        self.interval_iter()
+            .map(|interval| interval.remap_delete(deleted))
+            .collect()
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides the common error type for fallible interval operations.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::algorithm::PartitionError;
+use crate::algorithm::PartitionErrorKind;
+use crate::bed::BedError;
+use crate::interval::InvariantError;
+use crate::interval::ParseIntervalError;
+
+// Standard library imports.
+use std::fmt::Display;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalError
+////////////////////////////////////////////////////////////////////////////////
+/// An error returned by a fallible `Interval` or `Selection` operation.
+///
+/// The more specific [`ParseIntervalError`], [`InvariantError`],
+/// [`PartitionError`], and [`BedError`] types can be converted into an
+/// `IntervalError` with `?` or `From`.
+///
+/// Those types remain the direct results of the operations that produce
+/// them, so that callers can match on only the failures an operation can
+/// have. A `ParseIntervalError` also keeps the point type's own parse error
+/// type, and a `PartitionError` holds the offending intervals; it converts
+/// to its [`PartitionErrorKind`], as `IntervalError` is not generic over the
+/// point type. [`Selection::covers`] and [`Spanned::merge`] return data
+/// rather than an error on failure: the uncovered portion, or the unmerged
+/// values.
+///
+/// [`ParseIntervalError`]: ../interval/enum.ParseIntervalError.html
+/// [`InvariantError`]: ../interval/enum.InvariantError.html
+/// [`PartitionError`]: ../algorithm/enum.PartitionError.html
+/// [`BedError`]: ../bed/enum.BedError.html
+/// [`PartitionErrorKind`]: ../algorithm/enum.PartitionErrorKind.html
+/// [`Selection::covers`]: ../selection/struct.Selection.html#method.covers
+/// [`Spanned::merge`]: ../spanned/struct.Spanned.html#method.merge
+///
+/// # Example
+///
+/// ```rust
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::IntervalError;
+/// fn parse_span(s: &str) -> Result<Interval<i32>, IntervalError> {
+///     let interval: Interval<i32> = s.parse()?;
+///     if interval.is_empty() {
+///         return Err(IntervalError::EmptyResult);
+///     }
+///     Ok(interval)
+/// }
+///
+/// assert!(parse_span("[0, 5)").is_ok());
+/// assert!(matches!(parse_span("[0, x)"), Err(IntervalError::Parse(_))));
+/// assert!(matches!(parse_span("{}"), Err(IntervalError::EmptyResult)));
+///
+/// fn narrow(s: &str) -> Result<Interval<u8>, IntervalError> {
+///     parse_span(s)?.try_convert()
+/// }
+///
+/// assert!(narrow("[0, 255]").is_ok());
+/// assert!(matches!(narrow("[0, 256]"), Err(IntervalError::Overflow)));
+/// ```
+#[derive(Debug)]
+#[allow(variant_size_differences)]
+pub enum IntervalError {
+    /// The lower bound of an interval was greater than its upper bound.
+    ReversedBounds,
+    /// The operation produced an empty interval where a nonempty one was
+    /// required.
+    EmptyResult,
+    /// A computed bound point was not representable by the point type.
+    Overflow,
+    /// Interval notation failed to parse.
+    Parse(ParseIntervalError<Box<dyn std::error::Error + Send + Sync>>),
+    /// A float bound point was NaN.
+    NanBound,
+    /// An internal invariant of an interval or selection did not hold.
+    Invariant(InvariantError),
+    /// A collection of intervals did not partition a target interval.
+    Partition(PartitionErrorKind),
+    /// Reading or writing BED-style text failed.
+    Bed(BedError),
+}
+
+impl Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            IntervalError::ReversedBounds
+                => write!(f, "interval lower bound exceeds upper bound"),
+            IntervalError::EmptyResult
+                => write!(f, "interval is empty"),
+            IntervalError::Overflow
+                => write!(f, "interval bound point is not representable"),
+            IntervalError::Parse(ref e)
+                => Display::fmt(e, f),
+            IntervalError::NanBound
+                => write!(f, "interval bound point is NaN"),
+            IntervalError::Invariant(ref e)
+                => Display::fmt(e, f),
+            IntervalError::Partition(kind)
+                => Display::fmt(&kind, f),
+            IntervalError::Bed(ref e)
+                => Display::fmt(e, f),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            IntervalError::Parse(ParseIntervalError::InvalidPoint(ref e))
+                => Some(&**e),
+            IntervalError::Invariant(ref e) => Some(e),
+            IntervalError::Bed(ref e)       => Some(e),
+            _                               => None,
+        }
+    }
+}
+
+impl<E> From<ParseIntervalError<E>> for IntervalError
+    where E: std::error::Error + Send + Sync + 'static
+{
+    fn from(e: ParseIntervalError<E>) -> Self {
+        IntervalError::Parse(match e {
+            ParseIntervalError::InvalidNotation
+                => ParseIntervalError::InvalidNotation,
+            ParseIntervalError::InvalidPoint(e)
+                => ParseIntervalError::InvalidPoint(Box::new(e)),
+        })
+    }
+}
+
+impl From<InvariantError> for IntervalError {
+    fn from(e: InvariantError) -> Self {
+        IntervalError::Invariant(e)
+    }
+}
+
+impl<T> From<PartitionError<T>> for IntervalError {
+    fn from(e: PartitionError<T>) -> Self {
+        IntervalError::Partition(e.kind())
+    }
+}
+
+impl From<PartitionErrorKind> for IntervalError {
+    fn from(kind: PartitionErrorKind) -> Self {
+        IntervalError::Partition(kind)
+    }
+}
+
+impl From<BedError> for IntervalError {
+    fn from(e: BedError) -> Self {
+        IntervalError::Bed(e)
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides totally ordered floating point types for use as interval points.
+//!
+//! The primitive float types are only partially ordered, so they cannot be
+//! used as [`Interval`] points directly. The wrappers here order their values
+//! by `total_cmp`, which places `-0.0` before `0.0` and orders NaN values by
+//! sign and payload after the infinities, giving deterministic results in
+//! `Interval`s and `Selection`s. Successive values are found by stepping
+//! through the bit representations in this order.
+//!
+//! [`Interval`]: ../interval/struct.Interval.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::error::IntervalError;
+use crate::interval::Interval;
+
+// Standard library imports.
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Total float wrappers
+////////////////////////////////////////////////////////////////////////////////
+
+// Defines a totally ordered wrapper for a builtin float type.
+macro_rules! total_float_wrapper {
+    ($(#[$attr:meta])* $name:ident, $t:ident, $bits:ident, $key:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name(pub $t);
+
+        impl $name {
+            /// Constructs a new wrapper for the given value.
+            #[inline]
+            pub const fn new(value: $t) -> Self {
+                $name(value)
+            }
+
+            /// Returns the wrapped value.
+            #[inline]
+            pub const fn get(self) -> $t {
+                self.0
+            }
+
+            /// Returns `true` if the wrapped value is NaN.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.0.is_nan()
+            }
+
+            /// Returns the order key of the value. Keys are ordered as the
+            /// values are ordered by `total_cmp`.
+            #[inline]
+            pub(in crate) const fn key(self) -> $key {
+                let bits = self.0.to_bits() as $key;
+                // Flip the magnitude bits of negative values.
+                bits ^ ((((bits >> ($key::BITS - 1)) as $bits) >> 1) as $key)
+            }
+
+            /// Returns the value with the given order key.
+            #[inline]
+            pub(in crate) const fn from_key(key: $key) -> Self {
+                // Flipping the magnitude bits is its own inverse.
+                let bits = key ^ ((((key >> ($key::BITS - 1)) as $bits) >> 1) as $key);
+                $name($t::from_bits(bits as $bits))
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl Interval<$name> {
+            /// Constructs a new `Interval` from the given float bounds,
+            /// handling NaN bound points according to the given
+            /// [`NanPolicy`].
+            ///
+            /// [`NanPolicy`]: ../float/enum.NanPolicy.html
+            ///
+            /// # Errors
+            ///
+            /// Returns [`IntervalError::NanBound`] if a bound point is NaN
+            /// and the policy is [`NanPolicy::Error`].
+            ///
+            /// [`IntervalError::NanBound`]: ../error/enum.IntervalError.html#variant.NanBound
+            /// [`NanPolicy::Error`]: ../float/enum.NanPolicy.html#variant.Error
+            ///
+            /// # Panics
+            ///
+            /// Panics if a bound point is NaN and the policy is
+            /// [`NanPolicy::Panic`].
+            ///
+            /// [`NanPolicy::Panic`]: ../float/enum.NanPolicy.html#variant.Panic
+            pub fn try_from_float_bounds(
+                lower: Bound<$t>,
+                upper: Bound<$t>,
+                policy: NanPolicy)
+                -> Result<Self, IntervalError>
+            {
+                let has_nan = lower.clone().map_or(false, $t::is_nan)
+                    || upper.clone().map_or(false, $t::is_nan);
+                if has_nan {
+                    match policy {
+                        NanPolicy::Error => return Err(IntervalError::NanBound),
+                        NanPolicy::Panic => panic!("NaN interval bound"),
+                        NanPolicy::Empty => return Ok(Interval::empty()),
+                    }
+                }
+                Ok(Interval::new(lower.map($name), upper.map($name)))
+            }
+        }
+
+        impl From<$t> for $name {
+            fn from(value: $t) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $t {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+total_float_wrapper!(
+    /// An `f32` ordered by `total_cmp`, usable as an interval point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::float::TotalF32;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = Interval::closed(TotalF32(-1.0), TotalF32(1.0));
+    ///
+    /// assert!(interval.contains(&TotalF32(0.5)));
+    /// assert!(!interval.contains(&TotalF32(f32::NAN)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    TotalF32, f32, u32, i32);
+
+total_float_wrapper!(
+    /// An `f64` ordered by `total_cmp`, usable as an interval point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::float::TotalF64;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = Interval::right_open(TotalF64(-0.0), TotalF64(1.0));
+    ///
+    /// assert!(interval.contains(&TotalF64(-0.0)));
+    /// assert!(interval.contains(&TotalF64(0.0)));
+    /// assert!(!interval.contains(&TotalF64(1.0)));
+    /// assert_eq!(interval.supremum(), Some(TotalF64(1.0 - f64::EPSILON / 2.0)));
+    ///
+    /// // Zeros are distinct points.
+    /// assert!(!Interval::point(TotalF64(0.0)).contains(&TotalF64(-0.0)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    TotalF64, f64, u64, i64);
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NanPolicy
+////////////////////////////////////////////////////////////////////////////////
+/// Determines how NaN bound points are handled when constructing an
+/// `Interval` of totally ordered floats.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Bound;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::float::NanPolicy;
+/// # use normalize_interval::float::TotalF64;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let reading = Bound::Include(f64::NAN);
+///
+/// let res = Interval::<TotalF64>::try_from_float_bounds(
+///     Bound::Include(0.0), reading, NanPolicy::Error);
+/// assert!(res.is_err());
+///
+/// let res = Interval::<TotalF64>::try_from_float_bounds(
+///     Bound::Include(0.0), reading, NanPolicy::Empty);
+/// assert_eq!(res?, Interval::empty());
+///
+/// let res = Interval::<TotalF64>::try_from_float_bounds(
+///     Bound::Include(0.0), Bound::Exclude(1.0), NanPolicy::Error);
+/// assert!(res?.contains(&TotalF64(0.5)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// Return [`IntervalError::NanBound`].
+    ///
+    /// [`IntervalError::NanBound`]: ../error/enum.IntervalError.html#variant.NanBound
+    Error,
+    /// Panic.
+    Panic,
+    /// Return an empty `Interval`.
+    Empty,
+}
+
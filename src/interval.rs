@@ -13,19 +13,33 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::error::IntervalError;
+use crate::normalize::Bisect;
+use crate::normalize::CheckedAdd;
+use crate::normalize::CheckedSub;
 use crate::normalize::Finite;
+use crate::normalize::IntervalPoint;
 use crate::normalize::Normalize;
+use crate::normalize::Offset;
 use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+use crate::utility::Few;
 
 // Standard library imports.
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::iter::FusedIterator;
+use std::ops::Add;
 use std::ops::Range;
 use std::ops::RangeFrom;
 use std::ops::RangeFull;
-// use std::ops::RangeInclusive; // TODO: Add when RangeInclusive accessors stabilize.
+use std::ops::RangeInclusive;
 use std::ops::RangeTo;
 use std::ops::RangeToInclusive;
+use std::ops::Rem;
 use std::ops::Sub;
+use std::str::FromStr;
 
 
 
@@ -37,8 +51,32 @@ use std::ops::Sub;
 /// `Interval`s are [`Normalize`]d when created. For [`Finite`] types, open
 /// bounds will be converted to the nearest contained closed bound.
 ///
+/// Because of this, `Interval`s containing the same points have the same
+/// representation, so they compare equal and hash identically regardless of
+/// how they were constructed. In particular, all empty `Interval`s are equal.
+///
 /// [`Normalize`]: ../normalize/trait.Normalize.html
 /// [`Finite`]: ../normalize/trait.Finite.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use std::collections::HashSet;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut seen = HashSet::new();
+/// assert!(seen.insert(Interval::open(0, 5)));
+/// assert!(!seen.insert(Interval::closed(1, 4)));
+///
+/// assert!(seen.insert(Interval::open(3, 4)));
+/// assert!(!seen.insert(Interval::empty()));
+/// assert!(!seen.insert(Interval::closed(9, 2)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Interval<T>(pub (crate) RawInterval<T>);
 
@@ -46,7 +84,7 @@ pub struct Interval<T>(pub (crate) RawInterval<T>);
 // interval is normalized before returning.
 impl<T> Interval<T> 
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     ////////////////////////////////////////////////////////////////////////////
@@ -61,8 +99,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::new(Include(3), Exclude(7));
@@ -77,8 +115,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::new(Exclude(-3), Exclude(7));
@@ -93,8 +131,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::new(Exclude(7), Exclude(-7));
@@ -116,7 +154,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::empty();
@@ -136,7 +174,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::point(3);
@@ -156,7 +194,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(3, 7);
@@ -171,8 +209,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-3, 7);
@@ -187,8 +225,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(7, -7);
@@ -209,7 +247,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_open(3, 7);
@@ -224,8 +262,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_open(-3, 7);
@@ -240,8 +278,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_open(7, -7);
@@ -256,8 +294,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_open(5, 5);
@@ -278,7 +316,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_open(3, 7);
@@ -293,8 +331,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_open(-3, 7);
@@ -309,8 +347,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_open(7, -7);
@@ -325,8 +363,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_open(5, 5);
@@ -347,7 +385,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(3, 7);
@@ -360,8 +398,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(7, -7);
@@ -376,8 +414,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(5, 5);
@@ -398,7 +436,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_closed(3, 7);
@@ -413,8 +451,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_closed(-3, 7);
@@ -429,8 +467,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_closed(7, -7);
@@ -445,8 +483,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_closed(5, 5);
@@ -467,7 +505,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_closed(3, 7);
@@ -482,8 +520,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_closed(-3, 7);
@@ -498,8 +536,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_closed(7, -7);
@@ -514,8 +552,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_closed(5, 5);
@@ -537,7 +575,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_from(3);
@@ -552,8 +590,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # use std::i32;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
@@ -576,7 +614,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_to(3);
@@ -591,8 +629,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # use std::i32;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
@@ -615,7 +653,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_up_from(3);
@@ -630,8 +668,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # use std::i32;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
@@ -654,7 +692,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_up_to(3);
@@ -669,8 +707,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # use std::i32;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
@@ -692,7 +730,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::full();
@@ -707,8 +745,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # use std::i32;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
@@ -723,6 +761,99 @@ impl<T> Interval<T>
     pub fn full() -> Self {
         Interval(RawInterval::Full.normalized())
     }
+
+    /// Returns an [`IntervalBuilder`] for configuring the bounds of a new
+    /// `Interval` one at a time. Ends which are not given a point are
+    /// unbounded.
+    ///
+    /// [`IntervalBuilder`]: struct.IntervalBuilder.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = Interval::builder()
+    ///     .start(3)
+    ///     .open_start()
+    ///     .end(9)
+    ///     .closed_end()
+    ///     .build();
+    ///
+    /// assert_eq!(interval, Interval::left_open(3, 9));
+    ///
+    /// let inclusive = false;
+    /// let interval = Interval::builder()
+    ///     .start(3)
+    ///     .end(9)
+    ///     .end_included(inclusive)
+    ///     .build();
+    ///
+    /// assert_eq!(interval, Interval::right_open(3, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn builder() -> IntervalBuilder<T> {
+        IntervalBuilder::new()
+    }
+
+    /// Constructs the smallest closed `Interval` containing all of the given
+    /// points. If no points are given, an empty `Interval` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::enclose_points(vec![4, -2, 9, 3]);
+    ///
+    /// assert_eq!(interval, Interval::closed(-2, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::enclose_points(Vec::new());
+    ///
+    /// assert_eq!(interval, Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn enclose_points<I>(points: I) -> Self
+        where I: IntoIterator<Item=T>
+    {
+        let mut points = points.into_iter();
+        let first = match points.next() {
+            Some(point) => point,
+            None        => return Interval::empty(),
+        };
+
+        let (lower, upper) = points.fold(
+            (first.clone(), first),
+            |(lower, upper), point| {
+                if point < lower {
+                    (point, upper)
+                } else if point > upper {
+                    (lower, point)
+                } else {
+                    (lower, upper)
+                }
+            });
+
+        Interval::closed(lower, upper)
+    }
     
     ////////////////////////////////////////////////////////////////////////////
     // Conversion methods
@@ -733,8 +864,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # use std::i32;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
@@ -760,17 +891,19 @@ impl<T> Interval<T>
     ////////////////////////////////////////////////////////////////////////////
 
     /// Returns the lower [`Bound`] of the `Interval`, or `None` if the 
-    /// `Interval` is [`empty`].
+    /// `Interval` is [`empty`]. The bound point is cloned; use
+    /// [`lower_bound_ref`] to inspect it by reference.
     ///
     /// [`Bound`]: bound/enum.Bound.html
     /// [`empty`]: #method.empty
+    /// [`lower_bound_ref`]: #method.lower_bound_ref
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -786,8 +919,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-3, 5);
@@ -803,17 +936,19 @@ impl<T> Interval<T>
     }
     
     /// Returns the upper [`Bound`] of the `Interval`, or `None` if the 
-    /// `Interval` is [`empty`].
+    /// `Interval` is [`empty`]. The bound point is cloned; use
+    /// [`upper_bound_ref`] to inspect it by reference.
     ///
     /// [`Bound`]: bound/enum.Bound.html
     /// [`empty`]: #method.empty
+    /// [`upper_bound_ref`]: #method.upper_bound_ref
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -829,8 +964,8 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-3, 5);
@@ -854,7 +989,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -870,7 +1005,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-3, 5);
@@ -895,7 +1030,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -911,7 +1046,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-3, 5);
@@ -926,23 +1061,155 @@ impl<T> Interval<T>
         self.0.supremum()
     }
 
+    /// Returns the lower [`Bound`] of the `Interval` by reference, or `None`
+    /// if the `Interval` is [`empty`].
+    ///
+    /// [`Bound`]: bound/enum.Bound.html
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.lower_bound_ref(), Some(Include(&-3)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn lower_bound_ref(&self) -> Option<Bound<&T>> {
+        self.0.as_ref().lower_bound()
+    }
+
+    /// Returns the upper [`Bound`] of the `Interval` by reference, or `None`
+    /// if the `Interval` is [`empty`].
+    ///
+    /// [`Bound`]: bound/enum.Bound.html
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.upper_bound_ref(), Some(Include(&5)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn upper_bound_ref(&self) -> Option<Bound<&T>> {
+        self.0.as_ref().upper_bound()
+    }
+
+    /// Returns a reference to the greatest lower bound of the `Interval`, or
+    /// `None` if the `Interval` is [`empty`] or unbounded below.
+    ///
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.infimum_ref(), Some(&-3));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn infimum_ref(&self) -> Option<&T> {
+        self.0.as_ref().infimum()
+    }
+
+    /// Returns a reference to the least upper bound of the `Interval`, or
+    /// `None` if the `Interval` is [`empty`] or unbounded above.
+    ///
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.supremum_ref(), Some(&5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn supremum_ref(&self) -> Option<&T> {
+        self.0.as_ref().supremum()
+    }
+
+    /// Returns the lower and upper [`Bound`]s of the `Interval`, or `None` if
+    /// the `Interval` is [`empty`].
+    ///
+    /// [`Bound`]: bound/enum.Bound.html
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.endpoints(), Some((Include(-3), Include(5))));
+    ///
+    /// let interval: Interval<i32> = Interval::empty();
+    /// assert_eq!(interval.endpoints(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn endpoints(&self) -> Option<(Bound<T>, Bound<T>)> {
+        match (self.lower_bound(), self.upper_bound()) {
+            (Some(l), Some(u)) => Some((l, u)),
+            _                  => None,
+        }
+    }
+
     /// Returns the size of the `Interval`, or `None` if it is either infinite
     /// or empty.
     ///
+    /// The size may be of any type produced by subtracting two points, and no
+    /// zero value is required for it.
+    ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 7);
     /// assert_eq!(interval.size(), Some(10));
+    ///
+    /// let interval: Interval<i32> = Interval::empty();
+    /// assert_eq!(interval.size(), None);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn size(&self) -> Option<T> where T: Sub<Output=T> {
+    pub fn size<W>(&self) -> Option<W> where T: Sub<Output=W> {
         match (self.infimum(), self.supremum()) {
             (Some(l), Some(u)) => Some(u - l),
             _                  => None,
@@ -959,7 +1226,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -985,7 +1252,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -1011,7 +1278,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -1042,7 +1309,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_open(-3, 5);
@@ -1059,7 +1326,7 @@ impl<T> Interval<T>
     /// 
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::empty();
@@ -1086,7 +1353,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_open(-3, 5);
@@ -1103,7 +1370,7 @@ impl<T> Interval<T>
     /// 
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_to(4);
@@ -1132,7 +1399,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::right_open(-3, 5);
@@ -1149,7 +1416,7 @@ impl<T> Interval<T>
     /// 
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_from(4);
@@ -1178,7 +1445,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::left_open(-3, 5);
@@ -1195,7 +1462,7 @@ impl<T> Interval<T>
     /// 
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_to(4);
@@ -1223,7 +1490,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -1240,7 +1507,7 @@ impl<T> Interval<T>
     /// 
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::empty();
@@ -1269,7 +1536,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -1298,7 +1565,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(-3, 5);
@@ -1327,7 +1594,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_to(-3);
@@ -1356,7 +1623,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-2, 4);
@@ -1386,7 +1653,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-2, 4);
@@ -1415,7 +1682,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(-2, 4);
@@ -1443,7 +1710,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::unbounded_to(-2);
@@ -1469,11 +1736,14 @@ impl<T> Interval<T>
 
     /// Returns `true` if the the interval contains the given point.
     ///
+    /// The point may be any borrowed form of the point type, but the ordering
+    /// on the borrowed form must match the ordering on the point type.
+    ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::closed(0, 20);
@@ -1484,11 +1754,165 @@ impl<T> Interval<T>
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// Points wrapping a key may be queried by the key:
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::borrow::Borrow;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::normalize::Finite;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Row(u32);
+    ///
+    /// impl Borrow<u32> for Row {
+    ///     fn borrow(&self) -> &u32 { &self.0 }
+    /// }
+    ///
+    /// impl Finite for Row {
+    ///     const MINIMUM: Row = Row(u32::MIN);
+    ///     const MAXIMUM: Row = Row(u32::MAX);
+    ///     fn pred(&self) -> Option<Row> { self.0.checked_sub(1).map(Row) }
+    ///     fn succ(&self) -> Option<Row> { self.0.checked_add(1).map(Row) }
+    /// }
+    ///
+    /// let rows = Interval::closed(Row(10), Row(20));
+    /// assert_eq!(rows.contains(&15u32), true);
+    /// assert_eq!(rows.contains(&25u32), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
     #[inline]
-    pub fn contains(&self, point: &T) -> bool {
+    pub fn contains<Q>(&self, point: &Q) -> bool
+        where
+            T: Borrow<Q>,
+            Q: Ord + ?Sized,
+    {
         self.0.contains(point)
     }
 
+    /// Returns the [`Position`] of the given point relative to the `Interval`,
+    /// or `None` if the `Interval` is empty. A point which is both the least
+    /// and greatest point of the `Interval` is [`AtStart`].
+    ///
+    /// [`Position`]: enum.Position.html
+    /// [`AtStart`]: enum.Position.html#variant.AtStart
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::interval::Position;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(0, 20);
+    ///
+    /// assert_eq!(interval.position(&0), Some(Position::Before));
+    /// assert_eq!(interval.position(&1), Some(Position::AtStart));
+    /// assert_eq!(interval.position(&10), Some(Position::Inside));
+    /// assert_eq!(interval.position(&19), Some(Position::AtEnd));
+    /// assert_eq!(interval.position(&20), Some(Position::After));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn position(&self, point: &T) -> Option<Position> {
+        let (l, u) = match (self.lower_bound_ref(), self.upper_bound_ref()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return None,
+        };
+        let position = match l {
+            Bound::Include(l) if point <  l => Position::Before,
+            Bound::Include(l) if point == l => Position::AtStart,
+            Bound::Exclude(l) if point <= l => Position::Before,
+            _ => match u {
+                Bound::Include(u) if point >  u => Position::After,
+                Bound::Include(u) if point == u => Position::AtEnd,
+                Bound::Exclude(u) if point >= u => Position::After,
+                _                               => Position::Inside,
+            },
+        };
+        Some(position)
+    }
+
+    /// Returns the ordering of the `Interval` relative to the given point:
+    /// [`Less`] if the `Interval` lies entirely before the point, [`Greater`]
+    /// if it lies entirely after the point, and [`Equal`] if it contains the
+    /// point.
+    ///
+    /// This is suitable as the comparator for [`slice::binary_search_by`]
+    /// over sorted, disjoint `Interval`s. Empty `Interval`s are [`Less`], so a
+    /// search treats them as lying before every point, as they do when kept
+    /// at the start of a sorted slice.
+    ///
+    /// [`Less`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html#variant.Less
+    /// [`Equal`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html#variant.Equal
+    /// [`Greater`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html#variant.Greater
+    /// [`slice::binary_search_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::cmp::Ordering;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::right_open(0, 20);
+    /// assert_eq!(interval.cmp_point(&-1), Ordering::Greater);
+    /// assert_eq!(interval.cmp_point(&0), Ordering::Equal);
+    /// assert_eq!(interval.cmp_point(&20), Ordering::Less);
+    ///
+    /// let sorted = [
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 14),
+    ///     Interval::closed(20, 24),
+    /// ];
+    /// assert_eq!(sorted.binary_search_by(|i| i.cmp_point(&12)), Ok(1));
+    /// assert_eq!(sorted.binary_search_by(|i| i.cmp_point(&7)), Err(1));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn cmp_point(&self, point: &T) -> Ordering {
+        match self.position(point) {
+            Some(Position::Before) => Ordering::Greater,
+            Some(Position::After)  => Ordering::Less,
+            Some(_)                => Ordering::Equal,
+            None                   => Ordering::Less,
+        }
+    }
+
+    /// Returns `true` if the the interval contains the given point, using the
+    /// given comparison function to order points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(0, 20);
+    /// let by_magnitude = |a: &i32, b: &i32| a.abs().cmp(&b.abs());
+    /// assert_eq!(interval.contains_by(&-15, by_magnitude), true);
+    ///
+    /// assert_eq!(interval.contains_by(&-25, by_magnitude), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn contains_by<F>(&self, point: &T, compare: F) -> bool
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        self.0.contains_by(point, compare)
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Set comparisons
     ////////////////////////////////////////////////////////////////////////////
@@ -1499,7 +1923,7 @@ impl<T> Interval<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let a: Interval<i32> = Interval::closed(-3, 5);
@@ -1517,96 +1941,465 @@ impl<T> Interval<T>
         self.0.intersects(&other.0)
     }
 
-    /// Returns `true` if the `Interval` shares a bound with the given 
+    /// Returns `true` if the `Interval` has no points in common with the given
     /// `Interval`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(5, 15);
-    /// assert_eq!(a.adjacent(&b), true);
+    /// let b: Interval<i32> = Interval::closed(8, 12);
+    /// assert_eq!(a.is_disjoint(&b), true);
     ///
     /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(8, 12);
-    /// assert_eq!(a.adjacent(&b), false);
+    /// let b: Interval<i32> = Interval::closed(5, 15);
+    /// assert_eq!(a.is_disjoint(&b), false);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn adjacent(&self, other: &Self) -> bool {
-        // TODO: Consider normalization steps adjacent.
-        self.0.adjacent(&other.0)
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        !self.intersects(other)
     }
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Set operations
-    ////////////////////////////////////////////////////////////////////////////
-
-    /// Returns `Interval`s containing all points not contained in the 
-    /// [`Interval`].
+    /// Returns `true` if the `Interval` contains exactly the same points as the
+    /// given `Interval`. All empty `Interval`s are the same set.
+    ///
+    /// Because `Interval`s are normalized, this is equivalent to `==`, but
+    /// states the intent of comparing as sets.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let interval: Interval<i32> = Interval::open(-3, 5);
-    /// 
-    /// assert_eq!(interval.complement().collect::<Vec<_>>(), 
-    ///     [Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
+    /// let a: Interval<i32> = Interval::closed(5, 1);
+    /// let b: Interval<i32> = Interval::open(3, 3);
+    /// assert!(a.same_set(&b));
+    /// assert!(a.same_set(&Interval::empty()));
+    ///
+    /// assert!(!a.same_set(&Interval::point(3)));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn complement(&self) -> impl Iterator<Item=Self> {
-        self.0
-            .complement()
-            .map(Normalize::normalized)
-            .map(Interval)
-    }
-    
-    /// Returns the largest `Interval` whose points are all contained entirely
-    /// within the `Interval` and the given `Interval`.
     ///
-    /// # Example
+    /// For [`Finite`] types, open and closed forms holding the same points are
+    /// the same set:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 7);
-    /// let b: Interval<i32> = Interval::closed(4, 13);
-    /// assert_eq!(a.intersect(&b), Interval::closed(4, 7));
+    /// let a: Interval<i32> = Interval::open(0, 5);
+    /// assert!(a.same_set(&Interval::closed(1, 4)));
+    /// assert!(a.same_set(&Interval::left_open(0, 4)));
+    /// assert!(a.same_set(&Interval::right_open(1, 5)));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn intersect(&self, other: &Self) -> Self {
-        self.0.intersect(&other.0).normalized().into()
+    #[inline]
+    pub fn same_set(&self, other: &Self) -> bool {
+        self == other
     }
 
-    /// Returns the `Interval`s containing all points in the `Interval` and the
-    /// given `Interval`.
+    /// Returns `true` if the `Interval` overlaps the given `Interval`, using
+    /// the given comparison function to order points.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 7);
-    /// let b: Interval<i32> = Interval::closed(4, 13);
-    /// assert_eq!(a.union(&b).collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 13)]);
+    /// let by_magnitude = |a: &i32, b: &i32| a.abs().cmp(&b.abs());
+    ///
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(4, 15);
+    /// assert_eq!(a.intersects_by(&b, by_magnitude), true);
+    ///
+    /// let a: Interval<i32> = Interval::closed(-12, -8);
+    /// let b: Interval<i32> = Interval::closed(1, 6);
+    /// assert_eq!(a.intersects_by(&b, by_magnitude), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersects_by<F>(&self, other: &Self, compare: F) -> bool
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        self.0.intersects_by(&other.0, compare)
+    }
+
+    /// Returns `true` if the `Interval` shares a bound with the given 
+    /// `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(5, 15);
+    /// assert_eq!(a.adjacent(&b), true);
+    ///
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(8, 12);
+    /// assert_eq!(a.adjacent(&b), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn adjacent(&self, other: &Self) -> bool {
+        // TODO: Consider normalization steps adjacent.
+        self.0.adjacent(&other.0)
+    }
+
+    /// Returns the distance from the `Interval` to the given point, or `None`
+    /// if the `Interval` is [`empty`]. The distance is zero (as given by
+    /// `D::default()`) if the point is contained in the `Interval`.
+    ///
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.distance_to_point(&2), Some(0));
+    /// assert_eq!(interval.distance_to_point(&-7), Some(4));
+    /// assert_eq!(interval.distance_to_point(&12), Some(7));
+    ///
+    /// let interval: Interval<i32> = Interval::empty();
+    /// assert_eq!(interval.distance_to_point(&2), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn distance_to_point<D>(&self, point: &T) -> Option<D>
+        where T: Sub<Output=D>, D: Default
+    {
+        if self.is_empty() {
+            return None;
+        }
+        if self.contains(point) {
+            return Some(D::default());
+        }
+
+        match (self.infimum_ref(), self.supremum_ref()) {
+            (Some(l), _) if point <= l => Some(l.clone() - point.clone()),
+            (_, Some(u)) if point >= u => Some(point.clone() - u.clone()),
+            _ => unreachable!("uncontained point within interval bounds"),
+        }
+    }
+
+    /// Returns the size of the gap between the `Interval` and the given
+    /// `Interval`, or `None` if either is [`empty`]. The distance is zero (as
+    /// given by `D::default()`) if the `Interval`s overlap.
+    ///
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(4, 15);
+    /// assert_eq!(a.distance_to(&b), Some(0));
+    ///
+    /// let b: Interval<i32> = Interval::closed(8, 12);
+    /// assert_eq!(a.distance_to(&b), Some(3));
+    /// assert_eq!(b.distance_to(&a), Some(3));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn distance_to<D>(&self, other: &Self) -> Option<D>
+        where T: Sub<Output=D>, D: Default
+    {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        if self.intersects(other) {
+            return Some(D::default());
+        }
+
+        match (self.supremum_ref(), other.infimum_ref()) {
+            (Some(u), Some(l)) if u <= l => return Some(l.clone() - u.clone()),
+            _ => (),
+        }
+        match (other.supremum_ref(), self.infimum_ref()) {
+            (Some(u), Some(l)) if u <= l => Some(l.clone() - u.clone()),
+            _ => unreachable!("disjoint intervals are unordered"),
+        }
+    }
+
+    /// Returns the fraction of the points of the `Interval` which are also
+    /// contained in the given `Interval`, or `0.0` if they are disjoint or the
+    /// `Interval` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 9);
+    /// let b: Interval<i32> = Interval::closed(7, 20);
+    ///
+    /// assert_eq!(a.overlap_fraction(&b), 0.3);
+    /// assert_eq!(b.overlap_fraction(&a), 3.0 / 14.0);
+    /// assert_eq!(a.overlap_fraction(&Interval::closed(15, 20)), 0.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn overlap_fraction(&self, other: &Self) -> f64 where T: Offset {
+        let total = self.point_count();
+        if total == 0.0 {
+            return 0.0;
+        }
+        self.intersect(other).point_count() / total
+    }
+
+    /// Returns the number of points in the `Interval`, rounded to the nearest
+    /// `f64`.
+    pub(in crate) fn point_count(&self) -> f64 where T: Offset {
+        match (self.infimum_ref(), self.supremum_ref()) {
+            (Some(l), Some(u)) => T::offset_between(l, u)
+                .map_or(0.0, |steps| steps as f64 + 1.0),
+            _                  => 0.0,
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `Interval`s containing all points not contained in the 
+    /// [`Interval`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(-3, 5);
+    /// 
+    /// assert_eq!(interval.complement().collect::<Vec<_>>(), 
+    ///     [Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement(&self) -> impl Iterator<Item=Self> {
+        self.0
+            .complement()
+            .map(Normalize::normalized)
+            .map(Interval)
+    }
+    
+    /// Returns the largest `Interval` whose points are all contained entirely
+    /// within the `Interval` and the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 7);
+    /// let b: Interval<i32> = Interval::closed(4, 13);
+    /// assert_eq!(a.intersect(&b), Interval::closed(4, 7));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.0.intersect(&other.0).normalized().into()
+    }
+
+    /// Returns the `Interval` of points common to all of the given
+    /// `Interval`s, or `None` if they have no points in common.
+    ///
+    /// Iteration stops as soon as the intersection becomes empty. The
+    /// intersection of no `Interval`s is the [`full`] `Interval`.
+    ///
+    /// [`full`]: #method.full
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let intersection = Interval::intersect_all(vec![
+    ///     Interval::closed(-3, 7),
+    ///     Interval::closed(4, 13),
+    ///     Interval::unbounded_to(6),
+    /// ]);
+    /// assert_eq!(intersection, Some(Interval::closed(4, 6)));
+    ///
+    /// let intersection = Interval::intersect_all(vec![
+    ///     Interval::closed(-3, 7),
+    ///     Interval::closed(9, 13),
+    ///     Interval::closed(0, 20),
+    /// ]);
+    /// assert_eq!(intersection, None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect_all<I>(intervals: I) -> Option<Self>
+        where I: IntoIterator<Item=Self>
+    {
+        let mut intersection = Interval::full();
+        for interval in intervals {
+            intersection = intersection.intersect(&interval);
+            if intersection.is_empty() { return None; }
+        }
+        Some(intersection)
+    }
+
+    /// Returns the portion of the `Interval` which lies within the given
+    /// bounding `Interval`.
+    ///
+    /// This is equivalent to [`intersect`], but is provided for clarity when
+    /// clipping an `Interval` to a viewport or domain.
+    ///
+    /// [`intersect`]: #method.intersect
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 17);
+    /// let view: Interval<i32> = Interval::right_open(0, 10);
+    /// assert_eq!(interval.restricted_to(&view), Interval::closed(0, 9));
+    ///
+    /// let interval: Interval<i32> = Interval::closed(12, 17);
+    /// assert_eq!(interval.restricted_to(&view), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn restricted_to(&self, bounds: &Self) -> Self {
+        self.intersect(bounds)
+    }
+
+    /// Returns the portion of the `Interval` which lies within the given
+    /// bounding `Interval`, as [`restricted_to`] does, but collapses a result
+    /// which would be empty to the point of the bounding `Interval` nearest to
+    /// the `Interval`. An empty `Interval` is returned only if either
+    /// `Interval` is empty.
+    ///
+    /// [`restricted_to`]: #method.restricted_to
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let view: Interval<i32> = Interval::right_open(0, 10);
+    ///
+    /// let cursor: Interval<i32> = Interval::closed(12, 17);
+    /// assert_eq!(cursor.restricted_to_collapsing(&view), Interval::point(9));
+    ///
+    /// let cursor: Interval<i32> = Interval::closed(-8, -3);
+    /// assert_eq!(cursor.restricted_to_collapsing(&view), Interval::point(0));
+    ///
+    /// let cursor: Interval<i32> = Interval::closed(3, 17);
+    /// assert_eq!(cursor.restricted_to_collapsing(&view),
+    ///     Interval::closed(3, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn restricted_to_collapsing(&self, bounds: &Self) -> Self {
+        let restricted = self.restricted_to(bounds);
+        if !restricted.is_empty() || self.is_empty() {
+            return restricted;
+        }
+        let nearest = match (self.supremum_ref(), bounds.infimum_ref()) {
+            (Some(u), Some(l)) if u <= l => bounds.infimum(),
+            _                            => bounds.supremum(),
+        };
+        restricted.collapse_to_point(nearest)
+    }
+
+    /// Returns the `Interval`, or if it is [`empty`], the `Interval`
+    /// containing only the given point. If no point is given, the `Interval`
+    /// is returned unchanged.
+    ///
+    /// [`empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 4);
+    /// let b: Interval<i32> = Interval::closed(8, 12);
+    /// assert_eq!(a.intersect(&b).collapse_to_point(a.supremum()),
+    ///     Interval::point(4));
+    ///
+    /// assert_eq!(a.collapse_to_point(Some(6)), a);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn collapse_to_point(self, point: Option<T>) -> Self {
+        match point {
+            Some(p) if self.is_empty() => Interval::point(p),
+            _                          => self,
+        }
+    }
+
+    /// Returns the `Interval`s containing all points in the `Interval` and the
+    /// given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 7);
+    /// let b: Interval<i32> = Interval::closed(4, 13);
+    /// assert_eq!(a.union(&b).collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 13)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1618,186 +2411,1856 @@ impl<T> Interval<T>
             .map(Normalize::normalized)
             .map(Interval)
     }
-    
-    /// Returns the `Interval`s containing all points in the `Interval` which
-    /// are not in the given `Interval`.
+
+    /// Returns the `Interval`s containing all points in the `Interval` and the
+    /// given `Interval`, merging them into one `Interval` if the gap between
+    /// them is smaller than the given tolerance. The gap is measured as in
+    /// [`distance_to`].
+    ///
+    /// [`distance_to`]: #method.distance_to
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 10);
+    /// let b: Interval<i32> = Interval::closed(13, 20);
+    /// assert_eq!(a.union_within(&b, 5).collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 20)]);
+    ///
+    /// assert_eq!(a.union_within(&b, 3).count(), 2);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_within<D>(&self, other: &Self, tolerance: D)
+        -> impl Iterator<Item=Self>
+        where T: Sub<Output=D>, D: Ord + Default
+    {
+        let within = self.distance_to(other)
+            .is_some_and(|gap| gap < tolerance);
+        if within {
+            Few::One(self.enclose(other))
+        } else {
+            let mut pieces = self.union(other);
+            Few::from((pieces.next(), pieces.next()))
+        }
+    }
+
+    /// Returns the [`Selection`] containing all points in any of the given
+    /// `Interval`s.
+    ///
+    /// [`Selection`]: ../selection/struct.Selection.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel = Interval::union_all(vec![
+    ///     Interval::closed(9, 13),
+    ///     Interval::closed(-3, 5),
+    ///     Interval::closed(4, 7),
+    ///     Interval::closed(14, 16),
+    /// ]);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 7), Interval::closed(9, 16)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_all<I>(intervals: I) -> Selection<T>
+        where I: IntoIterator<Item=Self>
+    {
+        intervals.into_iter().collect()
+    }
+    
+    /// Returns the `Interval`s containing all points in the `Interval` which
+    /// are not in the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 7);
+    /// let b: Interval<i32> = Interval::closed(4, 13);
+    /// assert_eq!(a.minus(&b).collect::<Vec<_>>(),
+    ///     [Interval::right_open(-3, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus(&self, other: &Self) -> impl Iterator<Item=Self> {
+        // TODO: Fix intervals that are adjacent after normalization.
+        self.0
+            .minus(&other.0)
+            .map(Normalize::normalized)
+            .map(Interval)
+    }
+
+    /// Returns the smallest `Interval` that contains all of the points
+    /// contained within the `Interval` and the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(9, 13);
+    /// assert_eq!(a.enclose(&b), Interval::closed(-3, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn enclose(&self, other: &Self) -> Self {
+        self.0.enclose(&other.0).normalized().into()
+    }
+
+    /// Returns the smallest closed `Interval` containing all of the points in 
+    /// this `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(-3, 7);
+    /// assert_eq!(interval.closure(), Interval::closed(-2, 6));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn closure(&self) -> Self {
+        self.0.closure().normalized().into()
+    }
+
+    /// Returns the largest open `Interval` whose points are all contained in
+    /// this `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 7);
+    /// assert_eq!(interval.interior(), Interval::closed(-2, 6));
+    ///
+    /// let interval: Interval<i32> = Interval::point(4);
+    /// assert_eq!(interval.interior(), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interior(&self) -> Self {
+        self.0.interior().normalized().into()
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Alignment operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Interval` with its bounds rounded to multiples of the
+    /// given step, according to the given [`Alignment`].
+    ///
+    /// [`Alignment`]: enum.Alignment.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is not greater than zero (as given by
+    /// `T::default()`), or if rounding a bound overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::interval::Alignment;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 17);
+    ///
+    /// assert_eq!(interval.aligned_to(5, Alignment::Outward),
+    ///     Interval::closed(-5, 20));
+    /// assert_eq!(interval.aligned_to(5, Alignment::Inward),
+    ///     Interval::closed(0, 15));
+    /// assert_eq!(interval.aligned_to(5, Alignment::Nearest),
+    ///     Interval::closed(-5, 15));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn aligned_to(&self, step: T, alignment: Alignment) -> Self
+        where T: Add<Output=T> + Sub<Output=T> + Rem<Output=T> + Default
+    {
+        assert!(step > T::default(), "alignment step must be positive");
+
+        let (l, u) = match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return self.clone(),
+        };
+
+        match alignment {
+            Alignment::Outward => Interval::new(
+                self.lower_bound()
+                    .expect("nonempty interval")
+                    .map(|_| align_down(l, step.clone())),
+                self.upper_bound()
+                    .expect("nonempty interval")
+                    .map(|_| align_up(u, step))),
+            Alignment::Inward => Interval::new(
+                self.lower_bound()
+                    .expect("nonempty interval")
+                    .map(|_| align_up(l, step.clone())),
+                self.upper_bound()
+                    .expect("nonempty interval")
+                    .map(|_| align_down(u, step))),
+            Alignment::Nearest => Interval::new(
+                self.lower_bound()
+                    .expect("nonempty interval")
+                    .map(|_| align_nearest(l, step.clone())),
+                self.upper_bound()
+                    .expect("nonempty interval")
+                    .map(|_| align_nearest(u, step))),
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Validation
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Checks the internal invariants of the `Interval`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntervalError::Invariant`] with an [`InvariantError`]
+    /// describing the first invariant which does not hold.
+    ///
+    /// [`IntervalError::Invariant`]: ../error/enum.IntervalError.html#variant.Invariant
+    /// [`InvariantError`]: enum.InvariantError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// interval.validate()?;
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<(), IntervalError> {
+        if self.0.clone().normalized() != self.0 {
+            return Err(InvariantError::Unnormalized.into());
+        }
+        Ok(())
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Point conversion
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Interval` with the given fallible function applied to its
+    /// bound points, or the first error returned by the function. Inclusion
+    /// and exclusion of the bounds are preserved.
+    ///
+    /// The function should be monotonic; if it reverses the order of the
+    /// bounds, the result will be empty. Errors are returned as given by the
+    /// function, which may itself return an [`IntervalError`].
+    ///
+    /// [`IntervalError`]: ../error/enum.IntervalError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::convert::TryFrom;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<u64> = Interval::closed(3, 7);
+    /// let narrowed: Interval<u32> = interval.try_map(u32::try_from)?;
+    /// assert_eq!(narrowed, Interval::closed(3, 7));
+    ///
+    /// let interval: Interval<u64> = Interval::closed(3, 1 << 40);
+    /// assert!(interval.try_map(u32::try_from).is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_map<U, F, E>(&self, mut f: F) -> Result<Interval<U>, E>
+        where
+            U: IntervalPoint,
+            RawInterval<U>: Normalize,
+            F: FnMut(T) -> Result<U, E>,
+    {
+        let (l, u) = match (self.lower_bound(), self.upper_bound()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Ok(Interval::empty()),
+        };
+        let mut try_bound = |b: Bound<T>| Ok(match b {
+            Bound::Include(p) => Bound::Include(f(p)?),
+            Bound::Exclude(p) => Bound::Exclude(f(p)?),
+            Bound::Infinite   => Bound::Infinite,
+        });
+        let l = try_bound(l)?;
+        let u = try_bound(u)?;
+        Ok(Interval::new(l, u))
+    }
+
+    /// Converts the `Interval` into an `Interval` over another point type.
+    ///
+    /// This serves in place of a `TryFrom<Interval<T>>` impl, which would
+    /// conflict with the standard library's reflexive conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntervalError::Overflow`] if either bound point cannot be
+    /// represented in the new point type.
+    ///
+    /// [`IntervalError::Overflow`]: ../error/enum.IntervalError.html#variant.Overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i64> = Interval::closed(-2, 5);
+    /// assert_eq!(interval.try_convert::<i8>()?, Interval::closed(-2, 5));
+    /// assert!(interval.try_convert::<u8>().is_err());
+    ///
+    /// // Normalized bounds are checked, so a full interval rarely narrows.
+    /// let full: Interval<i64> = Interval::full();
+    /// assert!(full.try_convert::<i32>().is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_convert<U>(&self) -> Result<Interval<U>, IntervalError>
+        where
+            U: IntervalPoint + TryFrom<T>,
+            RawInterval<U>: Normalize,
+    {
+        self.try_map(|p| U::try_from(p).map_err(|_| IntervalError::Overflow))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Float conversion
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `Interval` of integers from floating point bounds,
+    /// rounding them according to the given [`Alignment`].
+    ///
+    /// `Outward` alignment gives the smallest interval enclosing the
+    /// continuous one, while `Inward` alignment gives exactly the integers
+    /// contained by it, respecting excluded bounds. `Nearest` alignment rounds
+    /// each bound to the nearest integer. Bounds beyond the range of `T` are
+    /// clamped, and a `NaN` bound results in an empty interval.
+    ///
+    /// [`Alignment`]: enum.Alignment.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::interval::Alignment;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let l = Bound::Exclude(1.0);
+    /// let u = Bound::Include(4.5);
+    ///
+    /// assert_eq!(Interval::<i64>::from_float_bounds(l, u, Alignment::Outward),
+    ///     Interval::closed(1, 5));
+    /// assert_eq!(Interval::<i64>::from_float_bounds(l, u, Alignment::Inward),
+    ///     Interval::closed(2, 4));
+    /// assert_eq!(Interval::<i64>::from_float_bounds(l, u, Alignment::Nearest),
+    ///     Interval::closed(1, 5));
+    ///
+    /// let l = Bound::Exclude(2.0);
+    /// let u = Bound::Exclude(3.0);
+    /// assert!(Interval::<i64>::from_float_bounds(l, u, Alignment::Inward)
+    ///     .is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_float_bounds(
+        lower: Bound<f64>,
+        upper: Bound<f64>,
+        alignment: Alignment)
+        -> Self
+        where T: Finite + TryFrom<i128>
+    {
+        use Alignment::*;
+        use Bound::*;
+        let lower = match lower {
+            Include(x) | Exclude(x) if x.is_nan() => return Interval::empty(),
+            Include(x) => match alignment {
+                Outward => x.floor(),
+                Inward  => x.ceil(),
+                Nearest => (x + 0.5).floor(),
+            },
+            Exclude(x) => match alignment {
+                Outward => x.floor(),
+                Inward  => x.floor() + 1.0,
+                Nearest => (x + 0.5).floor(),
+            },
+            Infinite   => f64::NEG_INFINITY,
+        };
+        let upper = match upper {
+            Include(x) | Exclude(x) if x.is_nan() => return Interval::empty(),
+            Include(x) => match alignment {
+                Outward => x.ceil(),
+                Inward  => x.floor(),
+                Nearest => (x + 0.5).floor(),
+            },
+            Exclude(x) => match alignment {
+                Outward => x.ceil(),
+                Inward  => x.ceil() - 1.0,
+                Nearest => (x + 0.5).floor(),
+            },
+            Infinite   => f64::INFINITY,
+        };
+
+        let lower = match float_to_point::<T>(lower) {
+            Ok(l)                  => Include(l),
+            Err(Ordering::Less)    => Infinite,
+            Err(_)                 => return Interval::empty(),
+        };
+        let upper = match float_to_point::<T>(upper) {
+            Ok(u)                  => Include(u),
+            Err(Ordering::Greater) => Infinite,
+            Err(_)                 => return Interval::empty(),
+        };
+        Interval::new(lower, upper)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Subdivision operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Splits the `Interval` at the given fractions of its points, returning
+    /// the contiguous sub-`Interval`s between them. One more sub-`Interval` is
+    /// returned than the number of fractions given, and sub-`Interval`s which
+    /// receive no points are empty. Cut positions are rounded to the nearest
+    /// point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fractions are not ascending and within `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(0, 9);
+    ///
+    /// assert_eq!(interval.split_at_fractions(&[0.7]), [
+    ///     Interval::closed(0, 6),
+    ///     Interval::closed(7, 9),
+    /// ]);
+    /// assert_eq!(interval.split_at_fractions(&[0.25, 0.5, 1.0]), [
+    ///     Interval::closed(0, 2),
+    ///     Interval::closed(3, 4),
+    ///     Interval::closed(5, 9),
+    ///     Interval::empty(),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_at_fractions(&self, fractions: &[f64]) -> Vec<Self>
+        where T: Offset,
+    {
+        assert!(fractions.windows(2).all(|w| w[0] <= w[1])
+                && fractions.iter().all(|f| (0.0..=1.0).contains(f)),
+            "fractions must be ascending and within [0, 1]");
+
+        let (l, last) = match bound_offsets(self) {
+            Some(bounds) => bounds,
+            None         => return vec![
+                Interval::empty(); fractions.len() + 1],
+        };
+        let count = last as f64 + 1.0;
+
+        split_at_offsets(&l, last, fractions.iter()
+            .map(|fraction| offset_from_f64((fraction * count).round())))
+    }
+
+    /// Splits the `Interval` into the given number of contiguous
+    /// sub-`Interval`s whose bounds are geometrically spaced, so that each
+    /// sub-`Interval` spans roughly the same ratio of points. Sub-`Interval`s
+    /// which receive no points after rounding are empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bins is zero, or if the `Interval` contains a
+    /// point not greater than zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<u32> = Interval::closed(1, 1000);
+    ///
+    /// assert_eq!(interval.log_bins(3), [
+    ///     Interval::closed(1, 9),
+    ///     Interval::closed(10, 99),
+    ///     Interval::closed(100, 1000),
+    /// ]);
+    ///
+    /// let interval: Interval<u32> = Interval::closed(1, 2);
+    /// assert_eq!(interval.log_bins(3), [
+    ///     Interval::empty(),
+    ///     Interval::point(1),
+    ///     Interval::point(2),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn log_bins(&self, n: usize) -> Vec<Self>
+        where T: Offset,
+    {
+        assert!(n > 0, "number of bins must be positive");
+
+        let (l, last) = match bound_offsets(self) {
+            Some(bounds) => bounds,
+            None         => return vec![Interval::empty(); n],
+        };
+        let start = l.to_f64();
+        assert!(start > 0.0, "log bins require an interval of positive points");
+
+        let end = start + last as f64 + 1.0;
+        let ratio = (end / start).powf(1.0 / n as f64);
+
+        split_at_offsets(&l, last, (1..n)
+            .map(|k| offset_from_f64(
+                (start * ratio.powi(k as i32)).round() - start)))
+    }
+
+    /// Splits the `Interval` at its midpoint, returning the lower and upper
+    /// halves. If an integer `Interval` has an odd number of points, the lower
+    /// half receives the extra point. A [`TotalF64`] `Interval` is split at
+    /// its numeric [`midpoint`].
+    ///
+    /// [`TotalF64`]: ../float/struct.TotalF64.html
+    /// [`midpoint`]: #method.midpoint
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::float::TotalF64;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-4, 4);
+    /// assert_eq!(interval.bisect(),
+    ///     (Interval::closed(-4, 0), Interval::closed(1, 4)));
+    ///
+    /// let interval: Interval<i32> = Interval::point(3);
+    /// assert_eq!(interval.bisect(), (Interval::point(3), Interval::empty()));
+    ///
+    /// let interval = Interval::closed(TotalF64(1.0), TotalF64(2.0));
+    /// let (lower, upper) = interval.bisect();
+    /// assert_eq!(lower.supremum(), Some(TotalF64(1.5)));
+    /// assert!(upper.contains(&TotalF64(1.5000001)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn bisect(&self) -> (Self, Self) where T: Bisect {
+        let (l, u) = match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return (Interval::empty(), Interval::empty()),
+        };
+        let mid = T::bisector(&l, &u);
+        let upper = match mid.succ() {
+            Some(next) if next <= u => Interval::closed(next, u),
+            _                       => Interval::empty(),
+        };
+        (Interval::closed(l, mid), upper)
+    }
+
+    /// Repeatedly bisects the `Interval`, continuing with the lower half if
+    /// the given predicate returns `true` for it, and with the upper half
+    /// otherwise, until at most one point remains. Returns the remaining
+    /// `Interval`.
+    ///
+    /// For a predicate which is monotonic over the halves, this performs a
+    /// binary search.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::float::TotalF64;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// // Find the smallest point whose square is at least 50.
+    /// let interval: Interval<i32> = Interval::closed(0, 100);
+    /// let found = interval.bisect_until(|lower| {
+    ///     lower.supremum().map_or(false, |p| p * p >= 50)
+    /// });
+    /// assert_eq!(found, Interval::point(8));
+    ///
+    /// // Find the root of x² - 2 between 1 and 2.
+    /// let interval = Interval::closed(TotalF64(1.0), TotalF64(2.0));
+    /// let root = interval.bisect_until(|lower| {
+    ///     lower.supremum().map_or(false, |x| x.get() * x.get() >= 2.0)
+    /// });
+    /// assert_eq!(root, Interval::point(TotalF64(2f64.sqrt())));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn bisect_until<P>(&self, mut predicate: P) -> Self
+        where
+            T: Bisect,
+            P: FnMut(&Self) -> bool,
+    {
+        let mut current = self.clone();
+        while current.infimum() != current.supremum() {
+            let (lower, upper) = current.bisect();
+            current = if predicate(&lower) { lower } else { upper };
+        }
+        current
+    }
+
+    /// Recursively bisects the `Interval` while the given predicate returns
+    /// `true` for a sub-`Interval`, up to the given depth, and returns the
+    /// resulting leaf sub-`Interval`s in ascending order. Single points are
+    /// never subdivided.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// // Refine more finely near zero.
+    /// let interval: Interval<i32> = Interval::closed(0, 15);
+    /// let leaves = interval.refine(3, |sub| sub.contains(&0));
+    ///
+    /// assert_eq!(leaves, [
+    ///     Interval::closed(0, 1),
+    ///     Interval::closed(2, 3),
+    ///     Interval::closed(4, 7),
+    ///     Interval::closed(8, 15),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn refine<P>(&self, max_depth: usize, mut predicate: P) -> Vec<Self>
+        where
+            T: Bisect,
+            P: FnMut(&Self) -> bool,
+    {
+        let mut leaves = Vec::new();
+        if self.is_empty() {
+            return leaves;
+        }
+
+        let mut stack = vec![(self.clone(), 0)];
+        while let Some((current, depth)) = stack.pop() {
+            if depth >= max_depth
+                || current.infimum() == current.supremum()
+                || !predicate(&current)
+            {
+                leaves.push(current);
+                continue;
+            }
+            let (lower, upper) = current.bisect();
+            stack.push((upper, depth + 1));
+            stack.push((lower, depth + 1));
+        }
+        leaves
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Shift and extend operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Interval` with both of its bounds moved up by the given
+    /// delta.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.shift(4), Interval::closed(1, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn shift<D>(&self, delta: D) -> Self
+        where T: Add<D, Output=T>, D: Clone
+    {
+        self.map_bounds(
+            |l| l + delta.clone(),
+            |u| u + delta.clone())
+    }
+
+    /// Returns the `Interval` with both of its bounds moved down by the given
+    /// delta.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.shift_down(4), Interval::closed(-7, 1));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn shift_down<D>(&self, delta: D) -> Self
+        where T: Sub<D, Output=T>, D: Clone
+    {
+        self.map_bounds(
+            |l| l - delta.clone(),
+            |u| u - delta.clone())
+    }
+
+    /// Returns the `Interval` with its lower bound moved down by the given
+    /// delta.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.left_extend(4), Interval::closed(-7, 5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn left_extend<D>(&self, delta: D) -> Self
+        where T: Sub<D, Output=T>
+    {
+        self.map_bounds(|l| l - delta, |u| u)
+    }
+
+    /// Returns the `Interval` with its upper bound moved up by the given
+    /// delta.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.right_extend(4), Interval::closed(-3, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn right_extend<D>(&self, delta: D) -> Self
+        where T: Add<D, Output=T>
+    {
+        self.map_bounds(|l| l, |u| u + delta)
+    }
+
+    /// Returns the `Interval` with its lower bound moved up by the given
+    /// delta. If the delta exceeds the size of the `Interval`, an empty
+    /// `Interval` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.left_crop(4), Interval::closed(1, 5));
+    /// assert_eq!(interval.left_crop(10), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn left_crop<D>(&self, delta: D) -> Self
+        where T: Add<D, Output=T>
+    {
+        self.map_bounds(|l| l + delta, |u| u)
+    }
+
+    /// Returns the `Interval` with its upper bound moved down by the given
+    /// delta. If the delta exceeds the size of the `Interval`, an empty
+    /// `Interval` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.right_crop(4), Interval::closed(-3, 1));
+    /// assert_eq!(interval.right_crop(10), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn right_crop<D>(&self, delta: D) -> Self
+        where T: Sub<D, Output=T>
+    {
+        self.map_bounds(|l| l, |u| u - delta)
+    }
+
+    /// Returns the `Interval` with its lower bound moved up by the given
+    /// delta.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntervalError::EmptyResult`] if the delta exceeds the size of
+    /// the `Interval` so that no points remain, or [`IntervalError::Overflow`]
+    /// if the moved bound cannot be represented.
+    ///
+    /// [`IntervalError::EmptyResult`]: ../error/enum.IntervalError.html#variant.EmptyResult
+    /// [`IntervalError::Overflow`]: ../error/enum.IntervalError.html#variant.Overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalError;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.try_left_crop(8)?, Interval::point(5));
+    /// assert!(matches!(interval.try_left_crop(10),
+    ///     Err(IntervalError::EmptyResult)));
+    ///
+    /// let interval: Interval<u8> = Interval::closed(200, 250);
+    /// assert!(matches!(interval.try_left_crop(100),
+    ///     Err(IntervalError::Overflow)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_left_crop<D>(&self, delta: D) -> Result<Self, IntervalError>
+        where T: CheckedAdd<D>
+    {
+        let cropped = self.try_map_bounds(
+            |l| l.checked_add(&delta),
+            Some)?;
+        self.checked_crop(cropped)
+    }
+
+    /// Returns the `Interval` with its upper bound moved down by the given
+    /// delta.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntervalError::EmptyResult`] if the delta exceeds the size of
+    /// the `Interval` so that no points remain, or [`IntervalError::Overflow`]
+    /// if the moved bound cannot be represented.
+    ///
+    /// [`IntervalError::EmptyResult`]: ../error/enum.IntervalError.html#variant.EmptyResult
+    /// [`IntervalError::Overflow`]: ../error/enum.IntervalError.html#variant.Overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalError;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.try_right_crop(8)?, Interval::point(-3));
+    /// assert!(matches!(interval.try_right_crop(10),
+    ///     Err(IntervalError::EmptyResult)));
+    ///
+    /// let interval: Interval<u8> = Interval::closed(0, 5);
+    /// assert!(matches!(interval.try_right_crop(10),
+    ///     Err(IntervalError::Overflow)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_right_crop<D>(&self, delta: D) -> Result<Self, IntervalError>
+        where T: CheckedSub<D>
+    {
+        let cropped = self.try_map_bounds(
+            Some,
+            |u| u.checked_sub(&delta))?;
+        self.checked_crop(cropped)
+    }
+
+    /// Returns the given cropped `Interval`, or an error if cropping removed
+    /// all of the points of the `Interval`.
+    fn checked_crop(&self, cropped: Self) -> Result<Self, IntervalError> {
+        if cropped.is_empty() && !self.is_empty() {
+            Err(IntervalError::EmptyResult)
+        } else {
+            Ok(cropped)
+        }
+    }
+
+    /// Returns the `Interval` with the given fallible functions applied to the
+    /// points of its lower and upper bounds, or an error if either function
+    /// fails.
+    fn try_map_bounds<L, U>(&self, lower: L, upper: U)
+        -> Result<Self, IntervalError>
+        where L: FnOnce(T) -> Option<T>, U: FnOnce(T) -> Option<T>
+    {
+        match (self.lower_bound(), self.upper_bound()) {
+            (Some(l), Some(u)) => Ok(Interval::new(
+                checked_bound(l, lower)?,
+                checked_bound(u, upper)?)),
+            _                  => Ok(Interval::empty()),
+        }
+    }
+
+    /// Returns the `Interval` with the given functions applied to the points
+    /// of its lower and upper bounds.
+    fn map_bounds<L, U>(&self, lower: L, upper: U) -> Self
+        where L: FnOnce(T) -> T, U: FnOnce(T) -> T
+    {
+        match (self.lower_bound(), self.upper_bound()) {
+            (Some(l), Some(u)) => Interval::new(l.map(lower), u.map(upper)),
+            _                  => Interval::empty(),
+        }
+    }
+}
+
+impl<T> Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Returns an `Iterator` over consecutive sub-`Interval`s of the given
+    /// width which cover the `Interval`. Each bin includes its lower bound
+    /// and excludes its upper bound, and the last bin is clipped to the
+    /// `Interval`. If stepping by the width would overflow `T`, the remainder
+    /// of the `Interval` is returned as the last bin.
+    ///
+    /// The width may be of any type `D` for which `T` implements
+    /// [`CheckedAdd<D>`], so a point type may be binned by a distinct offset
+    /// type. The builtin integers implement it only for their own type.
+    ///
+    /// [`CheckedAdd<D>`]: ../normalize/trait.CheckedAdd.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not greater than zero (as given by
+    /// `D::default()`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(0, 9);
+    ///
+    /// assert_eq!(interval.bins(4).collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 3),
+    ///     Interval::closed(4, 7),
+    ///     Interval::closed(8, 9),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn bins<D>(&self, width: D) -> Bins<T, D>
+        where T: CheckedAdd<D>, D: PartialOrd + Default
+    {
+        assert!(width > D::default(), "bin width must be positive");
+        Bins {
+            remaining: self.clone(),
+            width,
+        }
+    }
+}
+
+/// Applies the given fallible function to the point of the given `Bound`,
+/// returning an overflow error if it fails.
+fn checked_bound<T, F>(bound: Bound<T>, f: F)
+    -> Result<Bound<T>, IntervalError>
+    where F: FnOnce(T) -> Option<T>
+{
+    let overflow = || IntervalError::Overflow;
+    Ok(match bound {
+        Bound::Include(p) => Bound::Include(f(p).ok_or_else(overflow)?),
+        Bound::Exclude(p) => Bound::Exclude(f(p).ok_or_else(overflow)?),
+        Bound::Infinite   => Bound::Infinite,
+    })
+}
+
+/// Rounds the given value down to the nearest multiple of the step.
+fn align_down<T>(value: T, step: T) -> T
+    where T: Ord + Clone + Add<Output=T> + Sub<Output=T> + Rem<Output=T> + Default
+{
+    let mut rem = value.clone() % step.clone();
+    if rem < T::default() {
+        rem = rem + step;
+    }
+    value - rem
+}
+
+/// Rounds the given value up to the nearest multiple of the step.
+fn align_up<T>(value: T, step: T) -> T
+    where T: Ord + Clone + Add<Output=T> + Sub<Output=T> + Rem<Output=T> + Default
+{
+    let down = align_down(value.clone(), step.clone());
+    if down == value {
+        down
+    } else {
+        down + step
+    }
+}
+
+/// Rounds the given value to the nearest multiple of the step, rounding
+/// halfway cases up.
+fn align_nearest<T>(value: T, step: T) -> T
+    where T: Ord + Clone + Add<Output=T> + Sub<Output=T> + Rem<Output=T> + Default
+{
+    let down = align_down(value.clone(), step.clone());
+    if down == value {
+        return down;
+    }
+    let up = down.clone() + step;
+    if value.clone() - down.clone() < up.clone() - value {
+        down
+    } else {
+        up
+    }
+}
+
+/// Returns the least point of the given `Interval` and the offset of its
+/// greatest point from it, or `None` if the `Interval` is empty.
+fn bound_offsets<T>(interval: &Interval<T>) -> Option<(T, u128)>
+    where T: Offset
+{
+    let (l, u) = (interval.infimum()?, interval.supremum()?);
+    let last = T::offset_between(&l, &u)?;
+    Some((l, last))
+}
+
+/// Splits the closed `Interval` from the given point to the given offset from
+/// it before each of the given ascending cut offsets. A cut offset of `None`
+/// lies after the end of the `Interval`.
+fn split_at_offsets<T, I>(point: &T, last: u128, cuts: I) -> Vec<Interval<T>>
+    where
+        T: Offset,
+        I: Iterator<Item=Option<u128>>,
+{
+    let mut parts = Vec::new();
+    // The offset of the least point not yet split off, if any remain.
+    let mut start = Some(0);
+    for cut in cuts {
+        let end = match cut {
+            Some(cut) => cut.checked_sub(1).map(|end| end.min(last)),
+            None      => Some(last),
+        };
+        parts.push(match (start, end) {
+            (Some(s), Some(e)) => closed_from_offsets(point, s, e),
+            _                  => Interval::empty(),
+        });
+        start = match cut {
+            Some(cut) if cut <= last => start.map(|s| s.max(cut)),
+            _                        => None,
+        };
+    }
+    parts.push(start.map_or_else(Interval::empty,
+        |s| closed_from_offsets(point, s, last)));
+    parts
+}
+
+/// Converts a non-negative integral float to an offset, or `None` if it is
+/// too large to be represented.
+fn offset_from_f64(value: f64) -> Option<u128> {
+    // `u128::MAX` rounds up to 2^128, so every smaller float fits.
+    if value < u128::MAX as f64 {
+        Some(value as u128)
+    } else {
+        None
+    }
+}
+
+/// Constructs the closed `Interval` between the given offsets from the given
+/// point, returning an empty `Interval` if the offsets are out of order.
+fn closed_from_offsets<T>(point: &T, lower: u128, upper: u128) -> Interval<T>
+    where T: Offset
+{
+    if lower > upper {
+        return Interval::empty();
+    }
+    let at = |steps| point.offset_by(steps).expect("offset out of range");
+    Interval::closed(at(lower), at(upper))
+}
+
+/// Converts an integral float to a finite point, returning the direction in
+/// which it lies outside of the range of `T` if it cannot be represented.
+fn float_to_point<T>(value: f64) -> Result<T, Ordering>
+    where T: TryFrom<i128>
+{
+    if value < i128::MIN as f64 {
+        return Err(Ordering::Less);
+    }
+    if value >= i128::MAX as f64 {
+        return Err(Ordering::Greater);
+    }
+    let n = value as i128;
+    T::try_from(n).map_err(|_| if n < 0 { Ordering::Less } else { Ordering::Greater })
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> From<RawInterval<T>> for Interval<T> 
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(raw_interval: RawInterval<T>) -> Self {
+        Interval(raw_interval.normalized())
+    }
+}
+
+// NOTE: Conflicts with From<RangeFull> convertion.
+impl<T> From<T> for Interval<T> 
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(point: T) -> Self {
+        Interval(RawInterval::Point(point).normalized())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> From<Range<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: Range<T>) -> Self {
+        Interval(RawInterval::right_open(r.start, r.end).normalized())
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeInclusive<T>) -> Self {
+        let (start, end) = r.into_inner();
+        Interval(RawInterval::closed(start, end).normalized())
+    }
+}
+
+impl<T> From<RangeFrom<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeFrom<T>) -> Self {
+        Interval(RawInterval::From(r.start).normalized())
+    }
+}
+
+impl<T> From<RangeTo<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeTo<T>) -> Self {
+        Interval(RawInterval::UpTo(r.end).normalized())
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeToInclusive<T>) -> Self {
+        Interval(RawInterval::To(r.end).normalized())
+    }
+}
+
+impl<T> From<RangeFull> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(_r: RangeFull) -> Self {
+        Interval(RawInterval::Full.normalized())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Comparison traits
+////////////////////////////////////////////////////////////////////////////////
+// Ranges are compared by the points they contain, following the standard
+// library's notion of emptiness, so `3..3` is equal to any empty `Interval`.
+impl<T> PartialEq<Range<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Range<T>) -> bool {
+        if other.start >= other.end {
+            return self.is_empty();
+        }
+        let range = Interval::right_open(
+            other.start.clone(),
+            other.end.clone());
+        *self == range
+    }
+}
+
+impl<T> PartialEq<RangeInclusive<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &RangeInclusive<T>) -> bool {
+        if other.is_empty() {
+            return self.is_empty();
+        }
+        let range = Interval::closed(
+            other.start().clone(),
+            other.end().clone());
+        *self == range
+    }
+}
+
+impl<T> PartialEq<Interval<T>> for Range<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Interval<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T> PartialEq<Interval<T>> for RangeInclusive<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Interval<T>) -> bool {
+        other == self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Position
+////////////////////////////////////////////////////////////////////////////////
+/// The position of a point relative to an [`Interval`]. This is returned by
+/// the [`Interval::position`] method.
+///
+/// [`Interval`]: struct.Interval.html
+/// [`Interval::position`]: struct.Interval.html#method.position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The point is less than every point of the interval.
+    Before,
+    /// The point is the least point of the interval.
+    AtStart,
+    /// The point is contained in the interval, but is not its least or
+    /// greatest point.
+    Inside,
+    /// The point is the greatest point of the interval.
+    AtEnd,
+    /// The point is greater than every point of the interval.
+    After,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Alignment
+////////////////////////////////////////////////////////////////////////////////
+/// Determines how the bounds of an [`Interval`] are rounded when aligning it to
+/// a step.
+///
+/// [`Interval`]: struct.Interval.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Round the bounds away from each other, so that the aligned interval
+    /// contains the original.
+    Outward,
+    /// Round the bounds toward each other, so that the aligned interval is
+    /// contained by the original.
+    Inward,
+    /// Round each bound to the nearest step, rounding halfway cases upward.
+    /// The aligned interval need not contain or be contained by the original.
+    Nearest,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// EncloseBuilder
+////////////////////////////////////////////////////////////////////////////////
+/// Incrementally builds the smallest [`Interval`] containing a stream of points
+/// and `Interval`s.
+///
+/// [`Interval`]: struct.Interval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval::EncloseBuilder;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut hull: EncloseBuilder<i32> = EncloseBuilder::new();
+/// assert_eq!(hull.current(), &Interval::empty());
+///
+/// hull.push(4);
+/// hull.push(-2);
+/// hull.push_interval(&Interval::closed(7, 9));
+/// assert_eq!(hull.current(), &Interval::closed(-2, 9));
+///
+/// hull.push_interval(&Interval::empty());
+/// assert_eq!(hull.finish(), Interval::closed(-2, 9));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EncloseBuilder<T> {
+    /// The smallest `Interval` containing everything pushed so far.
+    hull: Interval<T>,
+}
+
+impl<T> EncloseBuilder<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `EncloseBuilder` with an empty hull.
+    #[inline]
+    pub fn new() -> Self {
+        EncloseBuilder { hull: Interval::empty() }
+    }
+
+    /// Extends the hull to contain the given point.
+    pub fn push(&mut self, point: T) {
+        self.hull = self.hull.enclose(&Interval::point(point));
+    }
+
+    /// Extends the hull to contain the given `Interval`.
+    pub fn push_interval(&mut self, interval: &Interval<T>) {
+        self.hull = self.hull.enclose(interval);
+    }
+
+    /// Returns the smallest `Interval` containing everything pushed so far.
+    #[inline]
+    pub fn current(&self) -> &Interval<T> {
+        &self.hull
+    }
+
+    /// Consumes the `EncloseBuilder`, returning the smallest `Interval`
+    /// containing everything pushed.
+    #[inline]
+    pub fn finish(self) -> Interval<T> {
+        self.hull
+    }
+}
+
+impl<T> Default for EncloseBuilder<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        EncloseBuilder::new()
+    }
+}
+
+impl<T> Extend<T> for EncloseBuilder<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, points: I) where I: IntoIterator<Item=T> {
+        for point in points {
+            self.push(point);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalBuilder
+////////////////////////////////////////////////////////////////////////////////
+/// Builds an [`Interval`] by configuring each bound separately. Constructed
+/// by [`Interval::builder`].
+///
+/// Each end is unbounded until given a point, and is closed unless made open.
+/// The openness of an end may be set before or after its point.
+///
+/// [`Interval`]: struct.Interval.html
+/// [`Interval::builder`]: struct.Interval.html#method.builder
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let interval = Interval::builder().open_start().start(3).build();
+/// assert_eq!(interval, Interval::unbounded_from(4));
+///
+/// let interval = Interval::builder()
+///     .start(3)
+///     .end(9)
+///     .unbounded_start()
+///     .build();
+/// assert_eq!(interval, Interval::unbounded_to(9));
+///
+/// let interval: Interval<i32> = Interval::builder().build();
+/// assert_eq!(interval, Interval::full());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalBuilder<T> {
+    /// The start point, or `None` if the start is unbounded.
+    start: Option<T>,
+    /// The end point, or `None` if the end is unbounded.
+    end: Option<T>,
+    /// Whether the start point is included.
+    start_included: bool,
+    /// Whether the end point is included.
+    end_included: bool,
+}
+
+impl<T> IntervalBuilder<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `IntervalBuilder` with both ends unbounded.
+    #[inline]
+    pub fn new() -> Self {
+        IntervalBuilder {
+            start: None,
+            end: None,
+            start_included: true,
+            end_included: true,
+        }
+    }
+
+    /// Sets the start point.
+    #[inline]
+    pub fn start(mut self, point: T) -> Self {
+        self.start = Some(point);
+        self
+    }
+
+    /// Sets the end point.
+    #[inline]
+    pub fn end(mut self, point: T) -> Self {
+        self.end = Some(point);
+        self
+    }
+
+    /// Removes the start point, making the start unbounded.
+    #[inline]
+    pub fn unbounded_start(mut self) -> Self {
+        self.start = None;
+        self
+    }
+
+    /// Removes the end point, making the end unbounded.
+    #[inline]
+    pub fn unbounded_end(mut self) -> Self {
+        self.end = None;
+        self
+    }
+
+    /// Excludes the start point.
+    #[inline]
+    pub fn open_start(self) -> Self {
+        self.start_included(false)
+    }
+
+    /// Includes the start point.
+    #[inline]
+    pub fn closed_start(self) -> Self {
+        self.start_included(true)
+    }
+
+    /// Excludes the end point.
+    #[inline]
+    pub fn open_end(self) -> Self {
+        self.end_included(false)
+    }
+
+    /// Includes the end point.
+    #[inline]
+    pub fn closed_end(self) -> Self {
+        self.end_included(true)
+    }
+
+    /// Sets whether the start point is included.
+    #[inline]
+    pub fn start_included(mut self, included: bool) -> Self {
+        self.start_included = included;
+        self
+    }
+
+    /// Sets whether the end point is included.
+    #[inline]
+    pub fn end_included(mut self, included: bool) -> Self {
+        self.end_included = included;
+        self
+    }
+
+    /// Constructs the configured `Interval`. If the start point is greater
+    /// than the end point, an empty `Interval` will be returned.
+    pub fn build(self) -> Interval<T> {
+        let lower = bound_of(self.start, self.start_included);
+        let upper = bound_of(self.end, self.end_included);
+        Interval::new(lower, upper)
+    }
+
+    /// Constructs the configured `Interval`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntervalError::ReversedBounds`] if the start point is
+    /// greater than the end point.
+    ///
+    /// [`IntervalError::ReversedBounds`]: ../error/enum.IntervalError.html#variant.ReversedBounds
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 7);
-    /// let b: Interval<i32> = Interval::closed(4, 13);
-    /// assert_eq!(a.minus(&b).collect::<Vec<_>>(),
-    ///     [Interval::right_open(-3, 4)]);
+    /// assert!(Interval::builder().start(9).end(3).try_build().is_err());
+    ///
+    /// let interval = Interval::builder().start(3).end(3).try_build()?;
+    /// assert_eq!(interval, Interval::point(3));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn minus(&self, other: &Self) -> impl Iterator<Item=Self> {
-        // TODO: Fix intervals that are adjacent after normalization.
-        self.0
-            .minus(&other.0)
-            .map(Normalize::normalized)
-            .map(Interval)
+    pub fn try_build(self) -> Result<Interval<T>, IntervalError> {
+        if let (Some(start), Some(end)) = (&self.start, &self.end) {
+            if start > end {
+                return Err(IntervalError::ReversedBounds);
+            }
+        }
+        Ok(self.build())
     }
+}
 
-    /// Returns the smallest `Interval` that contains all of the points
-    /// contained within the `Interval` and the given `Interval`.
+/// Returns the `Bound` for the given optional point.
+fn bound_of<T>(point: Option<T>, included: bool) -> Bound<T> {
+    match point {
+        Some(p) if included => Bound::Include(p),
+        Some(p)             => Bound::Exclude(p),
+        None                => Bound::Infinite,
+    }
+}
+
+impl<T> Default for IntervalBuilder<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        IntervalBuilder::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Default
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Default for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        Interval::empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Display
+////////////////////////////////////////////////////////////////////////////////
+// Display using interval notation. The alternate form uses unicode symbols for
+// the empty set and infinity.
+impl<T> std::fmt::Display for Interval<T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> Interval<T> where T: std::fmt::Display {
+    /// Returns the `Interval` in LaTeX interval notation.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(9, 13);
-    /// assert_eq!(a.enclose(&b), Interval::closed(-3, 13));
+    /// let interval: Interval<i32> = Interval::closed(0, 5);
+    /// assert_eq!(interval.to_latex(), "[0, 5]");
+    ///
+    /// let interval: Interval<i32> = Interval::empty();
+    /// assert_eq!(interval.to_latex(), "\\emptyset");
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn enclose(&self, other: &Self) -> Self {
-        self.0.enclose(&other.0).normalized().into()
+    pub fn to_latex(&self) -> String {
+        self.0.to_latex()
     }
+}
 
-    /// Returns the smallest closed `Interval` containing all of the points in 
-    /// this `Interval`.
+
+////////////////////////////////////////////////////////////////////////////////
+// Parsing
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Parses an `Interval` from interval notation, using the given function
+    /// to parse each point.
+    ///
+    /// Bounded intervals are written with brackets and a comma separating the
+    /// points, such as `[0, 5)`, and points may not contain commas. Unbounded
+    /// ends are written `-inf` and `inf` (or `-∞` and `∞`). The empty interval
+    /// is written `{}` or `∅`, and a single point may be written alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseIntervalError`] if the notation is malformed or if a
+    /// point fails to parse.
+    ///
+    /// [`ParseIntervalError`]: enum.ParseIntervalError.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let interval: Interval<i32> = Interval::open(-3, 7);
-    /// assert_eq!(interval.closure(), Interval::closed(-2, 6));
+    /// let hex = |s: &str| i32::from_str_radix(s.trim_start_matches("0x"), 16);
+    ///
+    /// let interval = Interval::parse_with("[0x10, 0x20)", hex)?;
+    /// assert_eq!(interval, Interval::right_open(16, 32));
+    ///
+    /// let interval = Interval::parse_with("(-inf, 0xff]", hex)?;
+    /// assert_eq!(interval, Interval::unbounded_to(255));
+    ///
+    /// assert!(Interval::parse_with("[0x10, 0xzz]", hex).is_err());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn closure(&self) -> Self {
-        self.0.closure().normalized().into()
-    }
-}
+    pub fn parse_with<F, E>(s: &str, mut parse_point: F)
+        -> Result<Self, ParseIntervalError<E>>
+        where F: FnMut(&str) -> Result<T, E>
+    {
+        use ParseIntervalError::*;
+        let s = s.trim();
+        if s == "{}" || s == "∅" {
+            return Ok(Interval::empty());
+        }
 
+        let lower_include = match s.chars().next() {
+            Some('[') => true,
+            Some('(') => false,
+            _         => return parse_point(s)
+                .map(Interval::point)
+                .map_err(InvalidPoint),
+        };
+        let upper_include = match s.chars().last() {
+            Some(']') => true,
+            Some(')') => false,
+            _         => return Err(InvalidNotation),
+        };
 
-////////////////////////////////////////////////////////////////////////////////
-// Conversion traits
-////////////////////////////////////////////////////////////////////////////////
+        let inner = &s[1..s.len() - 1];
+        let (lower, upper) = match inner.split_once(',') {
+            Some((lower, upper)) => (lower.trim(), upper.trim()),
+            None                 => return Err(InvalidNotation),
+        };
 
-impl<T> From<RawInterval<T>> for Interval<T> 
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(raw_interval: RawInterval<T>) -> Self {
-        Interval(raw_interval.normalized())
+        let lower = match lower {
+            "-inf" | "-∞" => Bound::Infinite,
+            _ => {
+                let p = parse_point(lower).map_err(InvalidPoint)?;
+                if lower_include { Bound::Include(p) } else { Bound::Exclude(p) }
+            },
+        };
+        let upper = match upper {
+            "inf" | "+inf" | "∞" | "+∞" => Bound::Infinite,
+            _ => {
+                let p = parse_point(upper).map_err(InvalidPoint)?;
+                if upper_include { Bound::Include(p) } else { Bound::Exclude(p) }
+            },
+        };
+        Ok(Interval::new(lower, upper))
     }
 }
 
-// NOTE: Conflicts with From<RangeFull> convertion.
-impl<T> From<T> for Interval<T> 
+impl<T> FromStr for Interval<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint + FromStr,
         RawInterval<T>: Normalize,
 {
-    fn from(point: T) -> Self {
-        Interval(RawInterval::Point(point).normalized())
-    }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// Conversion traits
-////////////////////////////////////////////////////////////////////////////////
+    type Err = ParseIntervalError<T::Err>;
 
-impl<T> From<Range<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(r: Range<T>) -> Self {
-        Interval(RawInterval::right_open(r.start, r.end).normalized())
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Interval::parse_with(s, str::parse)
     }
 }
 
-// // TODO: Fix RangeInclusive once the accessors become available.
-// impl<T> From<RangeInclusive<T>> for Interval<T>
-//     where
-//         T: Ord + Clone,
-//         RawInterval<T>: Normalize,
-// {
-//     fn from(r: RangeInclusive<T>) -> Self {
-//         Interval(RawInterval::closed(
-//             r.next().unwrap(),
-//             r.next_back().unwrap()).normalized())
-//     }
-// }
-
-impl<T> From<RangeFrom<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(r: RangeFrom<T>) -> Self {
-        Interval(RawInterval::From(r.start).normalized())
-    }
+/// An error which can be returned when parsing an [`Interval`].
+///
+/// [`Interval`]: struct.Interval.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseIntervalError<E> {
+    /// The interval notation was malformed.
+    InvalidNotation,
+    /// A point of the interval failed to parse.
+    InvalidPoint(E),
 }
 
-impl<T> From<RangeTo<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(r: RangeTo<T>) -> Self {
-        Interval(RawInterval::UpTo(r.end).normalized())
+impl<E> std::fmt::Display for ParseIntervalError<E> where E: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ParseIntervalError::InvalidNotation
+                => write!(f, "invalid interval notation"),
+            ParseIntervalError::InvalidPoint(ref e)
+                => write!(f, "invalid interval point: {}", e),
+        }
     }
 }
 
-impl<T> From<RangeToInclusive<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
+impl<E> std::error::Error for ParseIntervalError<E>
+    where E: std::error::Error + 'static
 {
-    fn from(r: RangeToInclusive<T>) -> Self {
-        Interval(RawInterval::To(r.end).normalized())
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            ParseIntervalError::InvalidNotation     => None,
+            ParseIntervalError::InvalidPoint(ref e) => Some(e),
+        }
     }
 }
 
-impl<T> From<RangeFull> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(_r: RangeFull) -> Self {
-        Interval(RawInterval::Full.normalized())
-    }
-}
 
 ////////////////////////////////////////////////////////////////////////////////
-// Default
+// InvariantError
 ////////////////////////////////////////////////////////////////////////////////
-impl<T> Default for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn default() -> Self {
-        Interval::empty()
+/// An error describing a broken internal invariant of an [`Interval`] or
+/// [`Selection`]. This is returned by the [`Interval::validate`] and
+/// [`Selection::validate`] methods.
+///
+/// [`Interval`]: struct.Interval.html
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`Interval::validate`]: struct.Interval.html#method.validate
+/// [`Selection::validate`]: ../selection/struct.Selection.html#method.validate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvariantError {
+    /// An interval is not in its normalized form.
+    Unnormalized,
+    /// A selection contains an interval with no points.
+    EmptyMember,
+    /// The bounds of a selection do not alternate between lower and upper
+    /// bounds.
+    UnbalancedBounds,
+    /// A selection contains intervals which overlap or are adjacent.
+    Uncoalesced,
+}
+
+impl std::fmt::Display for InvariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            InvariantError::Unnormalized
+                => write!(f, "interval is not normalized"),
+            InvariantError::EmptyMember
+                => write!(f, "selection contains an empty interval"),
+            InvariantError::UnbalancedBounds
+                => write!(f, "selection bounds are unbalanced"),
+            InvariantError::Uncoalesced
+                => write!(f, "selection contains overlapping or adjacent \
+                    intervals"),
+        }
     }
 }
 
+impl std::error::Error for InvariantError {}
+
 
 ////////////////////////////////////////////////////////////////////////////////
 // Finite iteration support
 ////////////////////////////////////////////////////////////////////////////////
-impl<T> Interval<T> where T: Ord + Clone + Finite {
+impl<T> Interval<T> where T: IntervalPoint + Finite {
     /// Returns an `Iterator` over the points in the `Interval`. Only defined
     /// for `Finite` `Interval`s.
     ///
@@ -1805,7 +4268,7 @@ impl<T> Interval<T> where T: Ord + Clone + Finite {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(3, 7);
@@ -1819,7 +4282,7 @@ impl<T> Interval<T> where T: Ord + Clone + Finite {
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
+    /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let interval: Interval<i32> = Interval::open(3, 7);
@@ -1828,6 +4291,27 @@ impl<T> Interval<T> where T: Ord + Clone + Finite {
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// The number of remaining points is known exactly, and for point types
+    /// of up to 32 bits the iterator is an `ExactSizeIterator`.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-5, 5);
+    /// let mut iter = interval.iter();
+    /// assert_eq!(iter.len(), 11);
+    /// let _ = iter.next_back();
+    /// assert_eq!(iter.len(), 10);
+    ///
+    /// let interval: Interval<u64> = Interval::full();
+    /// assert_eq!(interval.iter().size_hint(), (usize::MAX, None));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
     pub fn iter(&self) -> Iter<T> {
         Iter {
             inner: self.clone(),
@@ -1838,7 +4322,7 @@ impl<T> Interval<T> where T: Ord + Clone + Finite {
 
 
 impl<T> IntoIterator for Interval<T>
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {
     type Item = T;
     type IntoIter = Iter<T>;
@@ -1853,13 +4337,13 @@ impl<T> IntoIterator for Interval<T>
 ////////////////////////////////////////////////////////////////////////////////
 /// An `Iterator` over the points in an `Interval`.
 #[derive(Debug)]
-pub struct Iter<T> where T: Ord + Clone {
+pub struct Iter<T> where T: IntervalPoint {
     /// The `Interval` being iterated over.
     inner: Interval<T>,
 }
 
 impl<T> Iterator for Iter<T>
-    where T: Ord + Clone + Finite
+    where T: IntervalPoint + Finite
 {
     type Item = T;
 
@@ -1876,10 +4360,23 @@ impl<T> Iterator for Iter<T>
             _ => unreachable!("iter for Finite interval with open lower bound"),
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.inner.infimum_ref(), self.inner.supremum_ref()) {
+            (Some(l), Some(u)) => match T::steps_between(l, u)
+                .map(|steps| steps.checked_add(1))
+            {
+                Some(Some(count)) => (count, Some(count)),
+                Some(None)        => (usize::MAX, None),
+                None              => (1, None),
+            },
+            _                  => (0, Some(0)),
+        }
+    }
 }
 
 impl<T> DoubleEndedIterator for Iter<T>
-    where T: Ord + Clone + Finite
+    where T: IntervalPoint + Finite
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         match self.inner.upper_bound() {
@@ -1898,5 +4395,64 @@ impl<T> DoubleEndedIterator for Iter<T>
 
 impl<T> FusedIterator for Iter<T> 
     where
-        T: Ord + Clone + Finite
+        T: IntervalPoint + Finite
+{}
+
+// Implements ExactSizeIterator for point types whose intervals can never hold
+// more points than a `usize` can count.
+macro_rules! iter_exact_size_impl {
+    ($($t:ident),*) => {
+        $(impl ExactSizeIterator for Iter<$t> {})*
+    };
+}
+
+iter_exact_size_impl![u8, u16, i8, i16];
+
+#[cfg(target_pointer_width = "64")]
+iter_exact_size_impl![u32, i32];
+
+////////////////////////////////////////////////////////////////////////////////
+// Bins
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over fixed-width sub-`Interval`s of an `Interval`.
+#[derive(Debug)]
+pub struct Bins<T, D = T> where T: IntervalPoint {
+    /// The portion of the `Interval` not yet binned.
+    remaining: Interval<T>,
+    /// The width of each bin.
+    width: D,
+}
+
+impl<T, D> Iterator for Bins<T, D>
+    where
+        T: IntervalPoint + CheckedAdd<D>,
+        RawInterval<T>: Normalize,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let lower = self.remaining.lower_bound()?;
+        let upper = match lower.as_ref() {
+            Some(l) => l.checked_add(&self.width),
+            None    => unreachable!("bins for interval with infinite lower bound"),
+        };
+
+        // If the next bin boundary overflows, the remainder is the last bin.
+        let bin = match upper {
+            Some(u) => self.remaining
+                .intersect(&Interval::new(lower, Bound::Exclude(u))),
+            None    => self.remaining.clone(),
+        };
+        self.remaining = self.remaining
+            .minus(&bin)
+            .next()
+            .unwrap_or_else(Interval::empty);
+        Some(bin)
+    }
+}
+
+impl<T, D> FusedIterator for Bins<T, D>
+    where
+        T: IntervalPoint + CheckedAdd<D>,
+        RawInterval<T>: Normalize,
 {}
@@ -0,0 +1,252 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a sorted vector of possibly overlapping intervals.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::algorithm::partition_point_by_start;
+use crate::bound::LowerBound;
+use crate::bound::UpperBound;
+use crate::interval::Interval;
+use crate::normalize::IntervalPoint;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::iter::FromIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalVec
+////////////////////////////////////////////////////////////////////////////////
+/// A vector of [`Interval`]s kept sorted by lower bound, and then by upper
+/// bound.
+///
+/// Unlike a [`Selection`], an `IntervalVec` keeps each inserted `Interval`
+/// as given, so overlapping and adjacent `Interval`s are not merged. Empty
+/// `Interval`s are never stored.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`Selection`]: ../selection/struct.Selection.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval_vec::IntervalVec;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut spans = IntervalVec::new();
+/// spans.insert(Interval::closed(10, 20));
+/// spans.insert(Interval::closed(0, 15));
+/// spans.insert(Interval::closed(12, 13));
+///
+/// assert_eq!(spans.as_slice(), &[
+///     Interval::closed(0, 15),
+///     Interval::closed(10, 20),
+///     Interval::closed(12, 13),
+/// ]);
+/// assert_eq!(spans.containing(&14).count(), 2);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalVec<T> {
+    /// The sorted, nonempty `Interval`s.
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> IntervalVec<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new empty `IntervalVec`.
+    #[inline]
+    pub fn new() -> Self {
+        IntervalVec { intervals: Vec::new() }
+    }
+
+    /// Returns the number of `Interval`s in the `IntervalVec`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns `true` if the `IntervalVec` holds no `Interval`s.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the sorted `Interval`s as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Returns an iterator over the sorted `Interval`s.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Inserts the given `Interval`, keeping the `IntervalVec` sorted. Empty
+    /// `Interval`s are ignored. Returns the index of the inserted `Interval`,
+    /// or `None` if it was empty.
+    pub fn insert(&mut self, interval: Interval<T>) -> Option<usize> {
+        let key = bounds_key(&interval)?;
+        let index = self.intervals
+            .partition_point(|i| bounds_key(i).is_none_or(|k| k <= key));
+        self.intervals.insert(index, interval);
+        Some(index)
+    }
+
+    /// Removes and returns the `Interval` at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Interval<T> {
+        self.intervals.remove(index)
+    }
+
+    /// Returns an iterator over the `Interval`s containing the given point.
+    ///
+    /// A binary search skips the `Interval`s beginning after the point, and
+    /// the rest are scanned.
+    pub fn containing<'a>(&'a self, point: &'a T)
+        -> impl Iterator<Item=&'a Interval<T>> + 'a
+    {
+        let end = partition_point_by_start(&self.intervals, point);
+        self.intervals[..end]
+            .iter()
+            .filter(move |interval| interval.contains(point))
+    }
+
+    /// Returns an iterator over the `Interval`s sharing any points with the
+    /// given `Interval`.
+    ///
+    /// A binary search skips the `Interval`s beginning after the given
+    /// `Interval`, and the rest are scanned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::interval_vec::IntervalVec;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let spans: IntervalVec<i32> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(3, 8),
+    ///     Interval::closed(10, 12),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(spans.overlapping(&Interval::closed(4, 9))
+    ///     .collect::<Vec<_>>(), [
+    ///         &Interval::closed(0, 4),
+    ///         &Interval::closed(3, 8),
+    ///     ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn overlapping<'a>(&'a self, interval: &'a Interval<T>)
+        -> impl Iterator<Item=&'a Interval<T>> + 'a
+    {
+        let end = match interval.supremum_ref() {
+            Some(upper) => partition_point_by_start(&self.intervals, upper),
+            None        => 0,
+        };
+        self.intervals[..end]
+            .iter()
+            .filter(move |i| i.intersects(interval))
+    }
+
+    /// Consumes the `IntervalVec`, returning the sorted `Interval`s.
+    #[inline]
+    pub fn into_vec(self) -> Vec<Interval<T>> {
+        self.intervals
+    }
+}
+
+/// Returns the bounds of the given `Interval` as an orderable key.
+fn bounds_key<T>(interval: &Interval<T>)
+    -> Option<(LowerBound<&T>, UpperBound<&T>)>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    match (interval.lower_bound_ref(), interval.upper_bound_ref()) {
+        (Some(l), Some(u)) => Some((LowerBound(l), UpperBound(u))),
+        _                  => None,
+    }
+}
+
+impl<T> Default for IntervalVec<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        IntervalVec::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+impl<T> From<Vec<Interval<T>>> for IntervalVec<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by(|a, b| bounds_key(a).cmp(&bounds_key(b)));
+        IntervalVec { intervals }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for IntervalVec<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item=Interval<T>>
+    {
+        IntervalVec::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T> IntoIterator for IntervalVec<T> {
+    type Item = Interval<T>;
+    type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalVec<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
@@ -53,12 +53,28 @@ pub(in crate) mod utility {
 mod test;
 
 // Public modules.
+pub mod algorithm;
+pub mod arithmetic;
+pub mod bed;
 pub mod bound;
+#[cfg(feature = "chrono")]
+pub mod calendar;
+pub mod compact;
+pub mod edit;
+pub mod error;
+pub mod float;
 pub mod interval;
+pub mod interval_vec;
 pub mod normalize;
 pub mod selection;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod spanned;
+pub mod specialized;
+pub mod wrapping;
 
 // Exports.
 pub use crate::bound::Bound;
+pub use crate::error::IntervalError;
 pub use crate::interval::Interval;
 pub use crate::selection::Selection;
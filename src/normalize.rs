@@ -12,9 +12,37 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 // Local imports.
+use crate::float::TotalF32;
+use crate::float::TotalF64;
 use crate::raw_interval::RawInterval;
 
 
+////////////////////////////////////////////////////////////////////////////////
+// IntervalPoint
+////////////////////////////////////////////////////////////////////////////////
+/// The requirements for a type to be used as the point type of an
+/// [`Interval`] or [`Selection`].
+///
+/// This trait is implemented automatically for any totally ordered, cloneable
+/// type. Optional capabilities are provided by traits which extend it: point
+/// types which can be stepped through should implement [`Finite`] to enable
+/// normalization and iteration, [`Offset`] to enable measuring and
+/// subdividing intervals, and [`Bisect`] to enable bisection. Point types
+/// which can be moved by an offset should implement [`CheckedAdd`] and
+/// [`CheckedSub`] to enable binning and fallible cropping.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`Finite`]: trait.Finite.html
+/// [`Offset`]: trait.Offset.html
+/// [`Bisect`]: trait.Bisect.html
+/// [`CheckedAdd`]: trait.CheckedAdd.html
+/// [`CheckedSub`]: trait.CheckedSub.html
+pub trait IntervalPoint: Ord + Clone {}
+
+impl<T> IntervalPoint for T where T: Ord + Clone {}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 // Finite
 ////////////////////////////////////////////////////////////////////////////////
@@ -23,7 +51,7 @@ use crate::raw_interval::RawInterval;
 ///
 /// [`Normalize`]: trait.Normalize.html
 /// [`Interval`]: ../interval/struct.Interval.html
-pub trait Finite: Sized {
+pub trait Finite: IntervalPoint {
     /// The minimum value of the type.
     const MINIMUM: Self;
 
@@ -35,6 +63,77 @@ pub trait Finite: Sized {
 
     /// Returns the next element after the given one.
     fn succ(&self) -> Option<Self>;
+
+    /// Returns the number of successor steps from the start element to the end
+    /// element, or `None` if the end precedes the start or the number of steps
+    /// cannot be represented as a `usize`.
+    ///
+    /// The default implementation always returns `None`.
+    fn steps_between(_start: &Self, _end: &Self) -> Option<usize> {
+        None
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Offset
+////////////////////////////////////////////////////////////////////////////////
+/// Provides exact offsets between the points of a [`Finite`] type. Used to
+/// measure and subdivide an [`Interval`] without overflowing near the bounds
+/// of the type.
+///
+/// [`Finite`]: trait.Finite.html
+/// [`Interval`]: ../interval/struct.Interval.html
+pub trait Offset: Finite {
+    /// Returns the number of successor steps from the start point to the end
+    /// point, or `None` if the end precedes the start.
+    fn offset_between(start: &Self, end: &Self) -> Option<u128>;
+
+    /// Returns the point the given number of successor steps after the given
+    /// point, or `None` if it cannot be represented.
+    fn offset_by(&self, steps: u128) -> Option<Self>;
+
+    /// Returns the value of the point as the nearest `f64`.
+    fn to_f64(&self) -> f64;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bisect
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the point at which to split an [`Interval`] into halves.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+pub trait Bisect: Finite {
+    /// Returns the greatest point of the lower half of the points from the
+    /// lower point to the upper point. The returned point is at least the
+    /// lower point, and is less than the upper point unless they are equal.
+    fn bisector(lower: &Self, upper: &Self) -> Self;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CheckedAdd & CheckedSub
+////////////////////////////////////////////////////////////////////////////////
+/// Provides overflow-checked addition of an offset to an interval point. Used
+/// to step through an [`Interval`] without overflowing near its bounds.
+///
+/// The offset type may differ from the point type. Implement
+/// `CheckedAdd<D>` for a point type to step through its `Interval`s by
+/// offsets of type `D`.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+pub trait CheckedAdd<D = Self>: IntervalPoint {
+    /// Returns the point offset by the given amount, or `None` if the result
+    /// cannot be represented.
+    fn checked_add(&self, offset: &D) -> Option<Self>;
+}
+
+/// Provides overflow-checked subtraction of an offset from an interval point.
+pub trait CheckedSub<D = Self>: IntervalPoint {
+    /// Returns the point offset down by the given amount, or `None` if the
+    /// result cannot be represented.
+    fn checked_sub(&self, offset: &D) -> Option<Self>;
 }
 
 
@@ -82,17 +181,21 @@ impl<T> Normalize for RawInterval<T> where T: Finite {
             Empty           => Empty,
             Point(p)        => Point(p),
             Open(l, r)      => match (l.succ(), r.pred()) {
-                (Some(l), Some(r)) => Closed(l, r),
+                (Some(l), Some(r)) => RawInterval::closed(l, r),
                 _                  => Empty,
             },
-            LeftOpen(l, r)  => l.succ().map_or(Empty, |l| Closed(l, r)),
-            RightOpen(l, r) => r.pred().map_or(Empty, |r| Closed(l, r)),
+            LeftOpen(l, r)  => l.succ()
+                .map_or(Empty, |l| RawInterval::closed(l, r)),
+            RightOpen(l, r) => r.pred()
+                .map_or(Empty, |r| RawInterval::closed(l, r)),
             Closed(l, r)    => Closed(l, r),
-            UpTo(r)         => r.pred().map_or(Empty, |r| Closed(T::MINIMUM, r)),
-            UpFrom(l)       => l.succ().map_or(Empty, |l| Closed(l, T::MAXIMUM)),
-            To(p)           => Closed(T::MINIMUM, p),
-            From(p)         => Closed(p, T::MAXIMUM),
-            Full            => Closed(T::MINIMUM, T::MAXIMUM),
+            UpTo(r)         => r.pred()
+                .map_or(Empty, |r| RawInterval::closed(T::MINIMUM, r)),
+            UpFrom(l)       => l.succ()
+                .map_or(Empty, |l| RawInterval::closed(l, T::MAXIMUM)),
+            To(p)           => RawInterval::closed(T::MINIMUM, p),
+            From(p)         => RawInterval::closed(p, T::MAXIMUM),
+            Full            => RawInterval::closed(T::MINIMUM, T::MAXIMUM),
         }
     }
 
@@ -150,6 +253,13 @@ macro_rules! std_integer_finite_impl {
             fn succ(&self) -> Option<Self> {
                 if *self != std::$t::MAX {Some(self + 1)} else {None}
             }
+
+            fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+                use std::convert::TryFrom;
+                let start = i128::try_from(*start).ok()?;
+                let end = i128::try_from(*end).ok()?;
+                usize::try_from(end.checked_sub(start)?).ok()
+            }
         })*
     };
 }
@@ -160,5 +270,147 @@ std_integer_finite_impl![
     i8, i16, i32, i64, i128, isize
 ];
 
+// Implements exact offsets for a single builtin integer type narrower than
+// 128 bits, by widening its points to a 128-bit integer of the same
+// signedness.
+macro_rules! std_integer_offset_impl {
+    // For each given type...
+    ($wide:ident: $($t:ident),*) => {
+        $(impl Offset for $t {
+            fn offset_between(start: &Self, end: &Self) -> Option<u128> {
+                <$wide as Offset>::offset_between(
+                    &(*start as $wide),
+                    &(*end as $wide))
+            }
+
+            fn offset_by(&self, steps: u128) -> Option<Self> {
+                use std::convert::TryFrom;
+                let point = (*self as $wide).offset_by(steps)?;
+                $t::try_from(point).ok()
+            }
+
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+// Provide implementations of Offset for builtin integer types.
+std_integer_offset_impl![u128: u8, u16, u32, u64, usize];
+std_integer_offset_impl![i128: i8, i16, i32, i64, isize];
+
+impl Offset for u128 {
+    fn offset_between(start: &Self, end: &Self) -> Option<u128> {
+        u128::checked_sub(*end, *start)
+    }
+
+    fn offset_by(&self, steps: u128) -> Option<Self> {
+        u128::checked_add(*self, steps)
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl Offset for i128 {
+    fn offset_between(start: &Self, end: &Self) -> Option<u128> {
+        if end < start {
+            return None;
+        }
+        // The difference of two `i128`s always fits in a `u128`, so the
+        // wrapped difference is exact.
+        Some(end.wrapping_sub(*start) as u128)
+    }
+
+    fn offset_by(&self, steps: u128) -> Option<Self> {
+        self.checked_add_unsigned(steps)
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+}
+
+// Implements bisection for a single builtin integer type, giving the lower
+// half the extra point of an odd number of points.
+macro_rules! std_integer_bisect_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Bisect for $t {
+            fn bisector(lower: &Self, upper: &Self) -> Self {
+                let half = <$t as Offset>::offset_between(lower, upper)
+                    .map_or(0, |steps| steps / 2);
+                lower.offset_by(half).expect("bisector out of range")
+            }
+        })*
+    };
+}
+
+// Provide implementations of Bisect for builtin integer types.
+std_integer_bisect_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+// Implements checked addition and subtraction for a single builtin integer
+// type.
+macro_rules! std_integer_checked_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl CheckedAdd for $t {
+            fn checked_add(&self, offset: &Self) -> Option<Self> {
+                $t::checked_add(*self, *offset)
+            }
+        }
+
+        impl CheckedSub for $t {
+            fn checked_sub(&self, offset: &Self) -> Option<Self> {
+                $t::checked_sub(*self, *offset)
+            }
+        })*
+    };
+}
+
+// Provide implementations of CheckedAdd and CheckedSub for builtin integer
+// types.
+std_integer_checked_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Total float Finite implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Implements normalization for a totally ordered float wrapper by stepping
+// through its order keys.
+macro_rules! total_float_finite_impl {
+    // For each given wrapper and key type...
+    ($($t:ident: $key:ident),*) => {
+        $(impl Finite for $t {
+            const MINIMUM: $t = $t::from_key($key::MIN);
+            const MAXIMUM: $t = $t::from_key($key::MAX);
+
+            fn pred(&self) -> Option<Self> {
+                self.key().checked_sub(1).map($t::from_key)
+            }
+
+            fn succ(&self) -> Option<Self> {
+                self.key().checked_add(1).map($t::from_key)
+            }
+
+            fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+                use std::convert::TryFrom;
+                let start = i128::from(start.key());
+                let end = i128::from(end.key());
+                usize::try_from(end.checked_sub(start)?).ok()
+            }
+        })*
+    };
+}
 
-// TODO: Use nextUp and nextDown IEEE 754 functions to normalize float values?
+// Provide implementations of Finite for the total float wrappers.
+total_float_finite_impl![TotalF32: i32, TotalF64: i64];
@@ -14,6 +14,7 @@ use crate::bound::Bound;
 use crate::utility::Few;
 
 // Standard library imports.
+use std::borrow::Borrow;
 use std::cmp::Ordering;
 
 
@@ -140,6 +141,28 @@ impl<T> RawInterval<T> where T: Ord + Clone {
         }
     }
 
+    ////////////////////////////////////////////////////////////////////////////
+    // Conversion methods
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Converts from `&RawInterval<T>` to `RawInterval<&T>`.
+    pub fn as_ref(&self) -> RawInterval<&T> {
+        use RawInterval::*;
+        match *self {
+            Empty                   => Empty,
+            Point(ref p)            => Point(p),
+            Open(ref l, ref r)      => Open(l, r),
+            LeftOpen(ref l, ref r)  => LeftOpen(l, r),
+            RightOpen(ref l, ref r) => RightOpen(l, r),
+            Closed(ref l, ref r)    => Closed(l, r),
+            UpTo(ref p)             => UpTo(p),
+            UpFrom(ref p)           => UpFrom(p),
+            To(ref p)               => To(p),
+            From(ref p)             => From(p),
+            Full                    => Full,
+        }
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Bound accessors
     ////////////////////////////////////////////////////////////////////////////
@@ -229,20 +252,51 @@ impl<T> RawInterval<T> where T: Ord + Clone {
         }
     }
 
-    /// Returns `true` if the interval contains the given point.
-    pub fn contains(&self, point: &T) -> bool {
+    /// Returns `true` if the interval contains the given point, which may be
+    /// any borrowed form of the point type.
+    pub fn contains<Q>(&self, point: &Q) -> bool
+        where
+            T: Borrow<Q>,
+            Q: Ord + ?Sized,
+    {
+        use RawInterval::*;
+        match *self {
+            Empty                   => false,
+            Point(ref p)            => point == p.borrow(),
+            Open(ref l, ref r)      => point > l.borrow() && point < r.borrow(),
+            LeftOpen(ref l, ref r)  => point > l.borrow() && point <= r.borrow(),
+            RightOpen(ref l, ref r) => point >= l.borrow() && point < r.borrow(),
+            Closed(ref l, ref r)    => point >= l.borrow() && point <= r.borrow(),
+            UpTo(ref p)             => point < p.borrow(),
+            UpFrom(ref p)           => point > p.borrow(),
+            To(ref p)               => point <= p.borrow(),
+            From(ref p)             => point >= p.borrow(),
+            Full                    => true,
+        }
+    }
+
+    /// Returns `true` if the interval contains the given point, using the
+    /// given comparison function to order points.
+    pub fn contains_by<F>(&self, point: &T, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> Ordering
+    {
         use RawInterval::*;
+        use Ordering::*;
         match *self {
             Empty                   => false,
-            Point(ref p)            => point == p,
-            Open(ref l, ref r)      => point > l && point < r,
-            LeftOpen(ref l, ref r)  => point > l && point <= r,
-            RightOpen(ref l, ref r) => point >= l && point < r,
-            Closed(ref l, ref r)    => point >= l && point <= r,
-            UpTo(ref p)             => point < p,
-            UpFrom(ref p)           => point > p,
-            To(ref p)               => point <= p,
-            From(ref p)             => point >= p,
+            Point(ref p)            => compare(point, p) == Equal,
+            Open(ref l, ref r)      => compare(point, l) == Greater
+                && compare(point, r) == Less,
+            LeftOpen(ref l, ref r)  => compare(point, l) == Greater
+                && compare(point, r) != Greater,
+            RightOpen(ref l, ref r) => compare(point, l) != Less
+                && compare(point, r) == Less,
+            Closed(ref l, ref r)    => compare(point, l) != Less
+                && compare(point, r) != Greater,
+            UpTo(ref p)             => compare(point, p) == Less,
+            UpFrom(ref p)           => compare(point, p) == Greater,
+            To(ref p)               => compare(point, p) != Greater,
+            From(ref p)             => compare(point, p) != Less,
             Full                    => true,
         }
     }
@@ -252,17 +306,44 @@ impl<T> RawInterval<T> where T: Ord + Clone {
     
     /// Returns `true` if the interval overlaps the given interval.
     pub fn intersects(&self, other: &Self) -> bool {
-        !self.intersect(other).is_empty()
+        // Compare by reference to avoid cloning the interval points.
+        !self.as_ref().intersect(&other.as_ref()).is_empty()
+    }
+
+    /// Returns `true` if the interval overlaps the given interval, using the
+    /// given comparison function to order points.
+    pub fn intersects_by<F>(&self, other: &Self, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        let (this, other) = (self.as_ref(), other.as_ref());
+        if this.is_empty() || other.is_empty() { return false; }
+
+        // Each interval must begin before the other one ends.
+        let mut precedes = |lb: Option<Bound<&T>>, ub: Option<Bound<&T>>| {
+            match (lb, ub) {
+                (Some(Bound::Include(l)), Some(Bound::Include(u)))
+                    => compare(l, u) != Ordering::Greater,
+                (Some(Bound::Include(l)), Some(Bound::Exclude(u))) |
+                (Some(Bound::Exclude(l)), Some(Bound::Include(u))) |
+                (Some(Bound::Exclude(l)), Some(Bound::Exclude(u)))
+                    => compare(l, u) == Ordering::Less,
+                _   => true,
+            }
+        };
+        precedes(this.lower_bound(), other.upper_bound()) &&
+            precedes(other.lower_bound(), this.upper_bound())
     }
 
     /// Returns `true` if the given intervals share any boundary points.
     pub fn adjacent(&self, other: &Self) -> bool {
-        let a = match (self.lower_bound(), other.upper_bound()) {
+        // Compare by reference to avoid cloning the interval points.
+        let (this, other) = (self.as_ref(), other.as_ref());
+        let a = match (this.lower_bound(), other.upper_bound()) {
             (Some(lb), Some(ub)) => lb.union_adjacent(&ub),
             _ => false,
 
         };
-        let b = match (self.upper_bound(), other.lower_bound()) {
+        let b = match (this.upper_bound(), other.lower_bound()) {
             (Some(ub), Some(lb)) => lb.union_adjacent(&ub),
             _ => false,
         };
@@ -376,6 +457,25 @@ impl<T> RawInterval<T> where T: Ord + Clone {
         }
     }
 
+    /// Returns the largest open interval whose points are all contained
+    /// within the interval.
+    pub fn interior(&self) -> Self {
+        use RawInterval::*;
+        match *self {
+            Empty                   => Empty,
+            Point(_)                => Empty,
+            Open(ref l, ref r)      |
+            LeftOpen(ref l, ref r)  |
+            RightOpen(ref l, ref r) |
+            Closed(ref l, ref r)    => RawInterval::open(l.clone(), r.clone()),
+            UpTo(ref p)             => UpTo(p.clone()),
+            UpFrom(ref p)           => UpFrom(p.clone()),
+            To(ref p)               => UpTo(p.clone()),
+            From(ref p)             => UpFrom(p.clone()),
+            Full                    => Full,
+        }
+    }
+
     // Bulk set operations
     ////////////////////////////////////////////////////////////////////////////
 
@@ -425,22 +525,44 @@ impl<T> RawInterval<T> where T: Ord + Clone {
     }
 }
 
-// Display using interval notation.
+impl<T> RawInterval<T> where T: std::fmt::Display {
+    /// Returns the interval in LaTeX interval notation.
+    pub fn to_latex(&self) -> String {
+        use RawInterval::*;
+        match *self {
+            Empty                   => "\\emptyset".to_string(),
+            Point(ref p)            => format!("\\{{{}\\}}", p),
+            Open(ref l, ref r)      => format!("({}, {})", l, r),
+            LeftOpen(ref l, ref r)  => format!("({}, {}]", l, r),
+            RightOpen(ref l, ref r) => format!("[{}, {})", l, r),
+            Closed(ref l, ref r)    => format!("[{}, {}]", l, r),
+            UpTo(ref p)             => format!("(-\\infty, {})", p),
+            UpFrom(ref p)           => format!("({}, \\infty)", p),
+            To(ref p)               => format!("(-\\infty, {}]", p),
+            From(ref p)             => format!("[{}, \\infty)", p),
+            Full                    => "(-\\infty, \\infty)".to_string(),
+        }
+    }
+}
+
+// Display using interval notation. The alternate form uses unicode symbols for
+// the empty set and infinity.
 impl<T> std::fmt::Display for RawInterval<T> where T: std::fmt::Display {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use RawInterval::*;
+        let (empty, inf) = if f.alternate() { ("∅", "∞") } else { ("{}", "inf") };
         match *self {
-            Empty                   => write!(f, "Ø"),
+            Empty                   => write!(f, "{}", empty),
             Point(ref p)            => write!(f, "{}", p),
             Open(ref l, ref r)      => write!(f, "({}, {})", l, r),
             LeftOpen(ref l, ref r)  => write!(f, "({}, {}]", l, r),
             RightOpen(ref l, ref r) => write!(f, "[{}, {})", l, r),
             Closed(ref l, ref r)    => write!(f, "[{}, {}]", l, r),
-            UpTo(ref p)             => write!(f, "(-∞, {})", p),
-            UpFrom(ref p)           => write!(f, "({}, ∞)", p),
-            To(ref p)               => write!(f, "(-∞, {})", p),
-            From(ref p)             => write!(f, "({}, ∞)", p),
-            Full                    => write!(f, "(-∞, ∞)"),
+            UpTo(ref p)             => write!(f, "(-{}, {})", inf, p),
+            UpFrom(ref p)           => write!(f, "({}, {})", p, inf),
+            To(ref p)               => write!(f, "(-{}, {}]", inf, p),
+            From(ref p)             => write!(f, "[{}, {})", p, inf),
+            Full                    => write!(f, "(-{}, {})", inf, inf),
         }
     }
 }
@@ -13,15 +13,35 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::bound::UpperBound;
+use crate::error::IntervalError;
 use crate::interval::Interval;
+use crate::interval::InvariantError;
 use crate::normalize::Normalize;
 use crate::normalize::Finite;
+use crate::normalize::IntervalPoint;
+use crate::normalize::Offset;
 use crate::raw_interval::RawInterval;
 use crate::tine_tree::TineTree;
 
 // Standard library imports.
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::iter::FromIterator;
 use std::iter::FusedIterator;
+use std::iter::Peekable;
+use std::ops::Add;
+use std::ops::BitAndAssign;
+use std::ops::BitOrAssign;
+use std::ops::BitXorAssign;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::RangeInclusive;
+use std::ops::Sub;
+use std::ops::SubAssign;
 
 
 
@@ -29,7 +49,11 @@ use std::iter::FusedIterator;
 // Selection<T>
 ////////////////////////////////////////////////////////////////////////////////
 /// A possibly noncontiguous collection of `Interval`s of the type `T`.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// `Selection`s are compared and hashed by the normalized `Interval`s they
+/// contain, so `Selection`s containing the same points are equal and hash
+/// identically regardless of how they were built.
+#[derive(Debug, Clone)]
 pub struct Selection<T>(TineTree<T>);
 
 // All intervals in the `TineTree` must be denormalized before insert and
@@ -37,7 +61,7 @@ pub struct Selection<T>(TineTree<T>);
 // intervals.
 impl<T> Selection<T> 
     where 
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize 
 {
     // Constructors
@@ -49,7 +73,7 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::new();
@@ -74,7 +98,7 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::full();
@@ -89,6 +113,93 @@ impl<T> Selection<T>
         Interval::full().into()
     }
 
+    /// Constructs a new `Selection` from pairs of bound points, using the
+    /// given function to construct an `Interval` from each pair. Any of the
+    /// `Interval` constructors may be given to select the bound style.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from_pairs(
+    ///     vec![(0, 5), (5, 9), (12, 20)],
+    ///     Interval::right_open);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 8),
+    ///     Interval::closed(12, 19),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_pairs<I, F>(pairs: I, mut interval: F) -> Self
+        where
+            I: IntoIterator<Item=(T, T)>,
+            F: FnMut(T, T) -> Interval<T>,
+    {
+        pairs.into_iter()
+            .map(|(l, u)| interval(l, u))
+            .collect()
+    }
+
+    /// Returns the least and greatest points of each of the `Interval`s in the
+    /// `Selection` as pairs. Both points of each pair are included in the
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// sel.union_in_place(Interval::closed(9, 13));
+    ///
+    /// assert_eq!(sel.to_pairs(), [(-2, 4), (9, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_pairs(&self) -> Vec<(T, T)> {
+        self.interval_iter()
+            .filter_map(|interval| interval.infimum().zip(interval.supremum()))
+            .collect()
+    }
+
+    /// Returns the `Interval`s of the `Selection` as a `Vec` of
+    /// `RangeInclusive`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = vec![0..=4, 3..=6, 7..=7, 10..=12]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(sel.to_ranges(), [0..=7, 10..=12]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_ranges(&self) -> Vec<RangeInclusive<T>> {
+        self.to_pairs()
+            .into_iter()
+            .map(|(l, u)| l..=u)
+            .collect()
+    }
+
     // Bound accessors
     ////////////////////////////////////////////////////////////////////////////
 
@@ -101,9 +212,9 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
@@ -119,9 +230,9 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
@@ -146,9 +257,9 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
@@ -164,9 +275,9 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Bound::*;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
@@ -190,8 +301,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
@@ -207,8 +318,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
@@ -233,8 +344,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
@@ -250,8 +361,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
@@ -266,6 +377,81 @@ impl<T> Selection<T>
         self.0.upper_bound().and_then(|b| b.as_ref().cloned())
     }
 
+    /// Returns an iterator over the lower and upper [`Bound`]s of each of the
+    /// `Interval`s in the `Selection`.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(9, 13));
+    ///
+    /// assert_eq!(sel.endpoints().collect::<Vec<_>>(), [
+    ///     (Include(-3), Include(5)),
+    ///     (Include(9), Include(13)),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn endpoints(&self) -> impl Iterator<Item=(Bound<T>, Bound<T>)> + '_ {
+        self.interval_iter()
+            .filter_map(|interval| interval.endpoints())
+    }
+
+    /// Returns an iterator over the boundary events of the `Selection` in
+    /// ascending order, as would be encountered by a sweep line. Each
+    /// `Interval` produces an [`Enter`] event for its lower [`Bound`]
+    /// followed by an [`Exit`] event for its upper [`Bound`]. The inclusion of
+    /// the bound point is given by the [`Bound`], and unbounded sides produce
+    /// events with an [`Infinite`] bound.
+    ///
+    /// [`Enter`]: enum.Boundary.html#variant.Enter
+    /// [`Exit`]: enum.Boundary.html#variant.Exit
+    /// [`Bound`]: ../bound/enum.Bound.html
+    /// [`Infinite`]: ../bound/enum.Bound.html#variant.Infinite
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::selection::Boundary::*;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(9, 13));
+    ///
+    /// assert_eq!(sel.boundary_events().collect::<Vec<_>>(), [
+    ///     (Include(-3), Enter),
+    ///     (Include(5), Exit),
+    ///     (Include(9), Enter),
+    ///     (Include(13), Exit),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn boundary_events(&self)
+        -> impl Iterator<Item=(Bound<T>, Boundary)> + '_
+    {
+        self.endpoints()
+            .flat_map(|(l, u)| {
+                std::iter::once((l, Boundary::Enter))
+                    .chain(std::iter::once((u, Boundary::Exit)))
+            })
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Query operations
     ////////////////////////////////////////////////////////////////////////////
@@ -276,8 +462,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
@@ -294,14 +480,197 @@ impl<T> Selection<T>
         self.0.is_empty()
     }
 
+    /// Returns the number of disjoint `Interval`s in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(9, 13));
+    /// sel.union_in_place(Interval::closed(6, 7));
+    /// assert_eq!(sel.interval_count(), 2);
+    ///
+    /// let sel: Selection<i32> = Selection::empty();
+    /// assert_eq!(sel.interval_count(), 0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interval_count(&self) -> usize {
+        self.0.interval_iter().count()
+    }
+
+    /// Returns summary statistics for the `Interval`s of the `Selection`,
+    /// computed in a single pass. Widths and gaps are measured as
+    /// [`Interval::size`] does, by subtracting points.
+    ///
+    /// [`Interval::size`]: ../interval/struct.Interval.html#method.size
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 12),
+    ///     Interval::closed(20, 30),
+    /// ].into_iter().collect();
+    ///
+    /// let stats = sel.stats();
+    /// assert_eq!(stats.count, 3);
+    /// assert_eq!(stats.total_width, 16);
+    /// assert_eq!(stats.min_width, Some(2));
+    /// assert_eq!(stats.max_width, Some(10));
+    /// assert_eq!(stats.mean_width(), Some(5));
+    /// assert_eq!(stats.min_gap, Some(6));
+    /// assert_eq!(stats.max_gap, Some(8));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn stats<W>(&self) -> SelectionStats<W>
+        where T: Sub<Output=W>, W: Ord + Clone + Add<Output=W> + Default,
+    {
+        let mut stats = SelectionStats {
+            count: 0,
+            total_width: W::default(),
+            min_width: None,
+            max_width: None,
+            min_gap: None,
+            max_gap: None,
+        };
+        let mut prev_supremum: Option<T> = None;
+
+        for interval in self.interval_iter() {
+            let (l, u) = match (interval.infimum(), interval.supremum()) {
+                (Some(l), Some(u)) => (l, u),
+                _                  => continue,
+            };
+            if let Some(prev) = prev_supremum.take() {
+                let gap = l.clone() - prev;
+                stats.min_gap = Some(min_of(stats.min_gap, gap.clone()));
+                stats.max_gap = Some(max_of(stats.max_gap, gap));
+            }
+            prev_supremum = Some(u.clone());
+
+            let width = u - l;
+            stats.count += 1;
+            stats.total_width = stats.total_width + width.clone();
+            stats.min_width = Some(min_of(stats.min_width, width.clone()));
+            stats.max_width = Some(max_of(stats.max_width, width));
+        }
+        stats
+    }
+
+    /// Returns an iterator over the gaps between the `Interval`s of the
+    /// `Selection`, each paired with its width. Widths are measured between
+    /// the neighboring `Interval`s as [`Interval::distance_to`] does, matching
+    /// the gaps reported by [`stats`].
+    ///
+    /// [`Interval::distance_to`]: ../interval/struct.Interval.html#method.distance_to
+    /// [`stats`]: #method.stats
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 12),
+    ///     Interval::closed(15, 30),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(sel.gaps_with_widths().collect::<Vec<_>>(), [
+    ///     (Interval::closed(5, 9), 6),
+    ///     (Interval::closed(13, 14), 3),
+    /// ]);
+    ///
+    /// let widest = sel.gaps_with_widths().max_by_key(|(_, width)| *width);
+    /// assert_eq!(widest, Some((Interval::closed(5, 9), 6)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gaps_with_widths<W>(&self)
+        -> impl Iterator<Item=(Interval<T>, W)> + '_
+        where T: Sub<Output=W>
+    {
+        self.interval_iter()
+            .zip(self.interval_iter().skip(1))
+            .filter_map(|(prev, next)| {
+                let gap = Interval::new(
+                    opposite_bound(prev.upper_bound()?),
+                    opposite_bound(next.lower_bound()?));
+                let width = next.infimum()? - prev.supremum()?;
+                Some((gap, width))
+            })
+    }
+
+    /// Checks the internal invariants of the `Selection`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntervalError::Invariant`] with an [`InvariantError`]
+    /// describing the first invariant which does not hold.
+    ///
+    /// [`IntervalError::Invariant`]: ../error/enum.IntervalError.html#variant.Invariant
+    /// [`InvariantError`]: ../interval/enum.InvariantError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.minus_in_place(Interval::point(0));
+    /// sel.validate()?;
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<(), IntervalError> {
+        if !self.0.is_balanced() {
+            return Err(InvariantError::UnbalancedBounds.into());
+        }
+        let mut count = 0;
+        for interval in self.interval_iter() {
+            if interval.is_empty() {
+                return Err(InvariantError::EmptyMember.into());
+            }
+            count += 1;
+        }
+        if self.interval_iter().collect::<Selection<T>>().interval_count()
+            != count
+        {
+            return Err(InvariantError::Uncoalesced.into());
+        }
+        Ok(())
+    }
+
     /// Returns `true` if the interval contains all points.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
@@ -324,8 +693,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Interval<i32> = Interval::open(-2, 4);
@@ -348,8 +717,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Interval::open(-2, 4).into();
@@ -376,8 +745,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Interval::open(-2, 4).into();
@@ -403,8 +772,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Interval::unbounded_to(-2).into();
@@ -425,12 +794,15 @@ impl<T> Selection<T>
 
     /// Returns `true` if the the `Selection` contains the given point.
     ///
+    /// The point may be any borrowed form of the point type, but the ordering
+    /// on the borrowed form must match the ordering on the point type.
+    ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::closed(0, 20));
@@ -442,10 +814,41 @@ impl<T> Selection<T>
     /// # }
     /// ```
     #[inline]
-    pub fn contains(&self, point: &T) -> bool {
+    pub fn contains<Q>(&self, point: &Q) -> bool
+        where
+            T: Borrow<Q>,
+            Q: Ord + ?Sized,
+    {
         self.0.contains(point)
     }
 
+    /// Returns `true` if the the `Selection` contains the given point, using
+    /// the given comparison function to order points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::closed(0, 20));
+    /// let by_magnitude = |a: &i32, b: &i32| a.abs().cmp(&b.abs());
+    /// assert_eq!(sel.contains_by(&-15, by_magnitude), true);
+    ///
+    /// assert_eq!(sel.contains_by(&-25, by_magnitude), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn contains_by<F>(&self, point: &T, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        self.interval_iter()
+            .any(|interval| interval.contains_by(point, &mut compare))
+    }
+
     // Set comparisons
     ////////////////////////////////////////////////////////////////////////////
     
@@ -455,8 +858,8 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
@@ -471,211 +874,1103 @@ impl<T> Selection<T>
     /// # }
     /// ```
     pub fn intersects(&self, other: &Self) -> bool {
-        // TODO: Make generic?
-        !self.0.intersect(&other.0).is_empty()
+        !self.is_disjoint(other)
     }
 
-    // Symmetric set operations
-    ////////////////////////////////////////////////////////////////////////////
-
-    /// Returns the `Selection` containing all points in not contained in the
-    /// `Selection`.
+    /// Returns `true` if the `Selection` has no points in common with the
+    /// given `Selection`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    // /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    // /// 
-    // /// assert_eq!(sel.complement().collect::<Vec<_>>(), 
-    // ///     vec![Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
-    /// # //-------------------------------------------------------------------
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// a.union_in_place(Interval::closed(20, 25));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(8, 12));
+    /// assert_eq!(a.is_disjoint(&b), true);
     ///
-    /// ```rust
-    /// # use std::error::Error;
-    /// # use std::i32;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// # //-------------------------------------------------------------------
-    /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
-    /// 
-    /// assert_eq!(sel.complement().iter().collect::<Vec<_>>(), vec![
-    ///     Interval::closed(i32::MIN, -4),
-    ///     Interval::closed(6, i32::MAX),
-    /// ]);
+    /// let b: Selection<i32> = Selection::from(Interval::closed(12, 20));
+    /// assert_eq!(a.is_disjoint(&b), false);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn complement(&self) -> Self {
-        Selection(self.0.complement())
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        // The tine trees hold denormalized intervals, which may overlap even
+        // when their normalized intervals are only adjacent, so the normalized
+        // intervals are compared instead.
+        self.intersection_iter(other).next().is_none()
     }
 
-    /// Returns the `Selection` containing all points in both the given
-    /// `Selection`s.
+    /// Returns `true` if the `Selection` contains exactly the same points as
+    /// the given `Selection`. All empty `Selection`s are the same set.
+    ///
+    /// `Selection`s are compared by their normalized `Interval`s, so this is
+    /// equivalent to `==`, but states the intent of comparing as sets.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.intersect(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(4, 7)]);
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    /// a.minus_in_place(Interval::closed(0, 4));
+    ///
+    /// let b: Selection<i32> = Selection::from(Interval::closed(7, 2));
+    /// assert!(a.same_set(&b));
+    /// assert!(a.same_set(&Selection::new()));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// [`Finite`] types will have their bounds closed:
+    /// For [`Finite`] types, `Selection`s built from open and closed forms
+    /// holding the same points are the same set, even when the forms split
+    /// the points differently:
     ///
     /// [`Finite`]: ../normalize/trait.Finite.html
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
-    /// assert_eq!(a.intersect(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(5, 6)]);
+    /// let a: Selection<i32> = vec![
+    ///     Interval::open(0, 3),
+    ///     Interval::right_open(3, 5),
+    /// ].into_iter().collect();
+    ///
+    /// let b: Selection<i32> = Selection::from(Interval::closed(1, 4));
+    /// assert!(a.same_set(&b));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn intersect(&self, other: &Self) -> Self {
-        Selection(self.0.intersect(&other.0))
+    #[inline]
+    pub fn same_set(&self, other: &Self) -> bool {
+        self == other
     }
 
-    /// Returns the `Selection` containing all points in either of the given
-    /// `Selection`s.
+    /// Returns `true` if the `Selection` overlaps the given `Selection`, using
+    /// the given comparison function to order points.
+    ///
+    /// The comparison function must agree with the ordering of `T`: if
+    /// `a < b`, then `compare(a, b)` must not return `Ordering::Greater`. The
+    /// `Interval`s of both `Selection`s are walked once in order, so a
+    /// comparison which does not agree may give an incorrect result.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(-3, 13)]);
+    /// let by_decade = |a: &i32, b: &i32| (a / 10).cmp(&(b / 10));
+    ///
+    /// let a: Selection<i32> = Selection::from(Interval::closed(20, 23));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(27, 35));
+    /// assert_eq!(a.intersects_by(&b, by_decade), true);
+    ///
+    /// let a: Selection<i32> = Selection::from(Interval::closed(12, 18));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(21, 26));
+    /// assert_eq!(a.intersects_by(&b, by_decade), false);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn intersects_by<F>(&self, other: &Self, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        let mut a_iter = self.interval_iter().peekable();
+        let mut b_iter = other.interval_iter().peekable();
+        while let (Some(a), Some(b)) = (a_iter.peek(), b_iter.peek()) {
+            if a.intersects_by(b, &mut compare) {
+                return true;
+            }
+
+            // Advance whichever interval ends first, as it cannot overlap
+            // anything further in the other selection.
+            let a_upper = a.upper_bound_ref().map(UpperBound);
+            let b_upper = b.upper_bound_ref().map(UpperBound);
+            if a_upper <= b_upper {
+                let _ = a_iter.next();
+            } else {
+                let _ = b_iter.next();
+            }
+        }
+        false
+    }
+
+    /// Returns the Jaccard similarity of the `Selection` and the given
+    /// `Selection`: the number of points in their intersection divided by the
+    /// number of points in their union. Computed in a single pass over both
+    /// `Selection`s without constructing either set. Two empty `Selection`s
+    /// have a similarity of `1.0`.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
-    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(-2, 12)]);
+    /// let a: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// let b: Selection<i32> = vec![
+    ///     Interval::closed(5, 9),
+    ///     Interval::closed(20, 24),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(a.jaccard(&b), 5.0 / 15.0);
+    /// assert_eq!(a.jaccard(&a), 1.0);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn union(&self, other: &Self) -> Self {
-        Selection(self.0.union(&other.0))
+    pub fn jaccard(&self, other: &Self) -> f64 where T: Offset {
+        let mut a_iter = self.interval_iter().filter(|i| !i.is_empty());
+        let mut b_iter = other.interval_iter().filter(|i| !i.is_empty());
+
+        let (mut a_total, mut b_total, mut shared) = (0.0, 0.0, 0.0);
+        let mut a = a_iter.next();
+        let mut b = b_iter.next();
+        if let Some(i) = &a { a_total += i.point_count(); }
+        if let Some(i) = &b { b_total += i.point_count(); }
+
+        while let (Some(ai), Some(bi)) = (&a, &b) {
+            shared += ai.intersect(bi).point_count();
+            if ai.supremum_ref() <= bi.supremum_ref() {
+                a = a_iter.next();
+                if let Some(i) = &a { a_total += i.point_count(); }
+            } else {
+                b = b_iter.next();
+                if let Some(i) = &b { b_total += i.point_count(); }
+            }
+        }
+        a_total += a_iter.map(|i| i.point_count()).sum::<f64>();
+        b_total += b_iter.map(|i| i.point_count()).sum::<f64>();
+
+        let union = a_total + b_total - shared;
+        if union == 0.0 {
+            1.0
+        } else {
+            shared / union
+        }
     }
 
-    /// Returns the `Selection` containing all points in the `Selection` which
-    /// are not in the given `Selection`s.
+    /// Returns `Ok` if the `Selection` contains every point in the given
+    /// `Interval`, or otherwise the `Selection` of points which are not
+    /// covered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the uncovered portions of the `Interval` as an `Err` if any
+    /// exist.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.minus(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::right_open(-3, 4)]);
+    /// let mut shifts: Selection<i32> = Selection::from(Interval::closed(0, 8));
+    /// shifts.union_in_place(Interval::closed(9, 16));
+    /// shifts.union_in_place(Interval::closed(20, 23));
+    ///
+    /// assert_eq!(shifts.covers(&Interval::closed(0, 16)), Ok(()));
+    ///
+    /// let gaps = shifts.covers(&Interval::closed(0, 23)).unwrap_err();
+    /// assert_eq!(gaps.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(17, 19)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn minus(&self, other: &Self) -> Self {
-        Selection(self.0.minus(&other.0))
+    pub fn covers(&self, target: &Interval<T>) -> Result<(), Self> {
+        // Subtracting denormalized intervals may leave intervals with no
+        // points, so they are renormalized to remove them.
+        let gaps: Self = Selection::from(target.clone())
+            .minus(self)
+            .interval_iter()
+            .collect();
+        if gaps.is_empty() {
+            Ok(())
+        } else {
+            Err(gaps)
+        }
     }
 
-    /// Returns the smallest `Interval` containing all of the points in the 
+    // Symmetric set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Selection` containing all points in not contained in the
     /// `Selection`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
-    /// let sel = a.union(&b);
-    ///
-    /// assert_eq!(sel.enclose(), Interval::left_open(-3, 13));
+    // /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    // /// 
+    // /// assert_eq!(sel.complement().collect::<Vec<_>>(), 
+    // ///     vec![Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn enclose(&self) -> Interval<T> {
-        Interval(self.0.enclose().normalized())
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::i32;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// 
+    /// assert_eq!(sel.complement().interval_iter().collect::<Vec<_>>(), vec![
+    ///     Interval::closed(i32::MIN, -4),
+    ///     Interval::closed(6, i32::MAX),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement(&self) -> Self {
+        Selection(self.0.complement())
+    }
+
+    /// Returns the `Selection` containing all points in the given universe
+    /// `Interval` which are not contained in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut busy: Selection<i32> = Selection::from(Interval::closed(7, 10));
+    /// busy.union_in_place(Interval::closed(12, 13));
+    /// busy.union_in_place(Interval::closed(16, 20));
+    ///
+    /// let hours = Interval::closed(9, 17);
+    /// assert_eq!(busy.complement_within(&hours).interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::point(11), Interval::closed(14, 15)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement_within(&self, universe: &Interval<T>) -> Self {
+        // Subtracting denormalized intervals may leave intervals with no
+        // points, so they are renormalized to remove them.
+        Selection::from(universe.clone())
+            .minus(self)
+            .interval_iter()
+            .collect()
+    }
+
+    /// Returns the `Selection` containing all points in both the given
+    /// `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(4, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
+    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(5, 6)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Self {
+        // Intersecting denormalized intervals may leave intervals with no
+        // points, so they are renormalized to remove them.
+        Selection(self.0.intersect(&other.0)).interval_iter().collect()
+    }
+
+    /// Returns an iterator over the `Interval`s of the intersection of the
+    /// `Selection` and the given `Selection`, computed lazily as the
+    /// `Selection`s are walked together.
+    ///
+    /// The `Interval`s are yielded in order, and are the `Interval`s which
+    /// [`intersect`] would produce, without allocating the result.
+    ///
+    /// [`intersect`]: #method.intersect
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = vec![
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(20, 30),
+    /// ].into_iter().collect();
+    /// let b: Selection<i32> = vec![
+    ///     Interval::closed(5, 22),
+    ///     Interval::closed(25, 27),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(a.intersection_iter(&b).collect::<Vec<_>>(), [
+    ///     Interval::closed(5, 10),
+    ///     Interval::closed(20, 22),
+    ///     Interval::closed(25, 27),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersection_iter<'t>(&'t self, other: &'t Self)
+        -> IntersectionIter<'t, T>
+    {
+        IntersectionIter {
+            a: self.interval_iter().peekable(),
+            b: other.interval_iter().peekable(),
+        }
+    }
+
+    /// Returns the `Selection` containing all points in either of the given
+    /// `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(-3, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
+    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(-2, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        Selection(self.0.union(&other.0))
+    }
+
+    /// Returns the `Selection` with each pair of neighboring `Interval`s
+    /// merged if the gap between them is smaller than the given tolerance. The
+    /// gap is measured as in [`Interval::distance_to`].
+    ///
+    /// [`Interval::distance_to`]: ../interval/struct.Interval.html#method.distance_to
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let events: Selection<i32> = vec![
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(12, 20),
+    ///     Interval::closed(21, 30),
+    ///     Interval::closed(50, 60),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(events.coalesce_within(5)
+    ///     .interval_iter()
+    ///     .collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 30), Interval::closed(50, 60)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn coalesce_within<D>(&self, tolerance: D) -> Self
+        where T: Sub<Output=D>, D: Ord + Default
+    {
+        let mut coalesced = Vec::new();
+        let mut current: Option<Interval<T>> = None;
+        for interval in self.interval_iter() {
+            current = Some(match current.take() {
+                Some(prev) if prev.distance_to(&interval)
+                    .is_some_and(|gap: D| gap < tolerance)
+                    => prev.enclose(&interval),
+                Some(prev) => {
+                    coalesced.push(prev);
+                    interval
+                },
+                None => interval,
+            });
+        }
+        coalesced.extend(current);
+        coalesced.into_iter().collect()
+    }
+
+    /// Returns the `Selection` with each `Interval` narrower than the given
+    /// width removed. Widths are measured as [`Interval::size`] does, and
+    /// `Interval`s without a size are never removed.
+    ///
+    /// [`Interval::size`]: ../interval/struct.Interval.html#method.size
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let detected: Selection<i32> = vec![
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(14, 15),
+    ///     Interval::closed(20, 30),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(detected.simplify(3).interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 10), Interval::closed(20, 30)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn simplify<W>(&self, min_width: W) -> Self
+        where T: Sub<Output=W>, W: Ord
+    {
+        self.interval_iter()
+            .filter(|interval| interval.size()
+                .is_none_or(|width: W| width >= min_width))
+            .collect()
+    }
+
+    /// Returns the `Selection` with each `Interval` narrower than the given
+    /// width merged into its nearest neighboring `Interval`, preferring the
+    /// preceding `Interval` when the gaps are equal. A narrow `Interval`
+    /// without neighbors is removed. Widths and gaps are measured as
+    /// [`Interval::size`] and [`Interval::distance_to`] do.
+    ///
+    /// [`Interval::size`]: ../interval/struct.Interval.html#method.size
+    /// [`Interval::distance_to`]: ../interval/struct.Interval.html#method.distance_to
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let detected: Selection<i32> = vec![
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(17, 18),
+    ///     Interval::closed(20, 30),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(detected.simplify_merging(3)
+    ///     .interval_iter()
+    ///     .collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 10), Interval::closed(17, 30)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn simplify_merging<W>(&self, min_width: W) -> Self
+        where T: Sub<Output=W>, W: Ord + Default
+    {
+        let intervals: Vec<Interval<T>> = self.interval_iter().collect();
+        if intervals.len() < 2 {
+            return self.simplify(min_width);
+        }
+
+        // Whether each interval is merged with the one following it.
+        let mut joined = vec![false; intervals.len()];
+        for (i, interval) in intervals.iter().enumerate() {
+            if interval.size().is_none_or(|width: W| width >= min_width) {
+                continue;
+            }
+            let prev_gap: Option<W> = i.checked_sub(1)
+                .and_then(|p| intervals[p].distance_to(interval));
+            let next_gap: Option<W> = intervals.get(i + 1)
+                .and_then(|n| interval.distance_to(n));
+            match (prev_gap, next_gap) {
+                (Some(p), Some(n)) if n < p => joined[i] = true,
+                (Some(_), _)                => joined[i - 1] = true,
+                (None, _)                   => joined[i] = true,
+            }
+        }
+        merge_joined(intervals, joined)
+    }
+
+    /// Reduces the `Selection` to at most the given number of `Interval`s by
+    /// merging neighboring `Interval`s across the narrowest gaps, preferring
+    /// earlier gaps when widths are equal. Returns the reduced `Selection`
+    /// along with the total width of the gaps filled. Gap widths are measured
+    /// as in [`gaps_with_widths`].
+    ///
+    /// A nonempty `Selection` is never reduced below one `Interval`.
+    ///
+    /// [`gaps_with_widths`]: #method.gaps_with_widths
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = vec![
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 12),
+    ///     Interval::closed(15, 30),
+    ///     Interval::closed(32, 40),
+    /// ].into_iter().collect();
+    ///
+    /// let (reduced, filled) = sel.reduce_to(2);
+    /// assert_eq!(reduced.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 40),
+    /// ]);
+    /// assert_eq!(filled, 5);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn reduce_to<W>(&self, count: usize) -> (Self, W)
+        where T: Sub<Output=W>, W: Ord + Default + Add<Output=W>
+    {
+        let intervals: Vec<Interval<T>> = self.interval_iter().collect();
+        let excess = intervals.len().saturating_sub(count.max(1));
+        if excess == 0 {
+            return (self.clone(), W::default());
+        }
+
+        let mut gaps: Vec<(W, usize)> = self.gaps_with_widths()
+            .enumerate()
+            .map(|(i, (_, width))| (width, i))
+            .collect();
+        gaps.sort();
+
+        // Whether each interval is merged with the one following it.
+        let mut joined = vec![false; intervals.len()];
+        let mut filled = W::default();
+        for (width, i) in gaps.into_iter().take(excess) {
+            joined[i] = true;
+            filled = filled + width;
+        }
+        (merge_joined(intervals, joined), filled)
+    }
+
+    /// Returns the `Interval`s of the `Selection` cut at each of the given
+    /// points, so that no returned `Interval` contains both a cut point and a
+    /// point preceding it. Each cut point begins a new `Interval`. The points
+    /// need not be sorted.
+    ///
+    /// The pieces are returned in order as a `Vec`, as they would be merged
+    /// back together in a `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = vec![
+    ///     Interval::closed(0, 15),
+    ///     Interval::closed(18, 25),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(sel.split_at_points(&[20, 10, 30]), [
+    ///     Interval::closed(0, 9),
+    ///     Interval::closed(10, 15),
+    ///     Interval::closed(18, 19),
+    ///     Interval::closed(20, 25),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_at_points(&self, points: &[T]) -> Vec<Interval<T>> {
+        let mut cuts = points.to_vec();
+        cuts.sort();
+        cuts.dedup();
+
+        let mut pieces = Vec::new();
+        let mut next_cut = 0;
+        for interval in self.interval_iter() {
+            // Cuts at or before the start of an interval cut nothing here or
+            // in any later interval.
+            if let Some(l) = interval.infimum_ref() {
+                while next_cut < cuts.len() && cuts[next_cut] <= *l {
+                    next_cut += 1;
+                }
+            }
+
+            let mut rest = interval;
+            for cut in &cuts[next_cut..] {
+                if rest.supremum_ref().is_some_and(|u| cut > u) {
+                    break;
+                }
+                let cut = cut.clone();
+                let before = rest.intersect(&Interval::new(
+                    Bound::Infinite,
+                    Bound::Exclude(cut.clone())));
+                if !before.is_empty() {
+                    pieces.push(before);
+                }
+                rest = rest.intersect(&Interval::new(
+                    Bound::Include(cut),
+                    Bound::Infinite));
+            }
+            if !rest.is_empty() {
+                pieces.push(rest);
+            }
+        }
+        pieces
+    }
+
+    /// Returns the `Selection` containing all points in the `Selection` which
+    /// are not in the given `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.minus(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::right_open(-3, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus(&self, other: &Self) -> Self {
+        Selection(self.0.minus(&other.0))
+    }
+
+    /// Returns the `Selection` containing all points in exactly one of the
+    /// `Selection` and the given `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// a.union_in_place(Interval::closed(20, 25));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(5, 22));
+    ///
+    /// assert_eq!(a.symmetric_difference(&b).interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(11, 19),
+    ///     Interval::closed(23, 25),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        // The difference of denormalized intervals may leave intervals which
+        // are adjacent after normalization, so they are renormalized to merge
+        // them.
+        Selection(self.0.symmetric_difference(&other.0))
+            .interval_iter()
+            .collect()
+    }
+
+    /// Returns the `Selection` containing all points in the `Selection` which
+    /// lie within the given bounding `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 2));
+    /// sel.union_in_place(Interval::closed(6, 17));
+    ///
+    /// let view: Interval<i32> = Interval::right_open(0, 10);
+    /// assert_eq!(sel.restricted_to(&view).interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 2), Interval::closed(6, 9)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn restricted_to(&self, bounds: &Interval<T>) -> Self {
+        let mut restricted = self.clone();
+        restricted.restrict_to(bounds);
+        restricted
+    }
+
+    /// Returns the smallest `Interval` containing all of the points in the 
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
+    /// let sel = a.union(&b);
+    ///
+    /// assert_eq!(sel.enclose(), Interval::left_open(-3, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn enclose(&self) -> Interval<T> {
+        Interval(self.0.enclose().normalized())
+    }
+
+    /// Returns the smallest closed `Interval` containing all of the points
+    /// in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
+    /// let sel = a.union(&b);
+    ///
+    /// assert_eq!(sel.enclose(), Interval::closed(-2, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn closure(&self) -> Interval<T> {
+        Interval(self.0.closure().normalized())
+    }
+
+    /// Returns the `Selection` containing the interior of each of the
+    /// `Interval`s in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(9, 13));
+    /// sel.union_in_place(Interval::point(20));
+    ///
+    /// assert_eq!(sel.interior().interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-2, 4), Interval::closed(10, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interior(&self) -> Self {
+        self.interval_iter()
+            .map(|interval| interval.interior())
+            .collect()
+    }
+
+    // Shift operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Selection` with all of its bounds moved up by the given
+    /// delta.
+    ///
+    /// Each `Interval` of the `Selection` is moved as [`Interval::shift`]
+    /// moves it, so a bound at the least or greatest value of a [`Finite`]
+    /// type is moved like any other bound.
+    ///
+    /// [`Interval::shift`]: ../interval/struct.Interval.html#method.shift
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if a moved bound overflows `T` and overflow checks are enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = vec![
+    ///     Interval::closed(-3, 5),
+    ///     Interval::closed(10, 12),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(sel.shift(4).interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(1, 9),
+    ///     Interval::closed(14, 16),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn shift<D>(&self, delta: D) -> Self
+        where T: Add<D, Output=T>, D: Clone
+    {
+        self.interval_iter()
+            .map(|interval| interval.shift(delta.clone()))
+            .collect()
     }
 
-    /// Returns the smallest closed `Interval` containing all of the points
-    /// in the `Selection`.
+    /// Returns the `Selection` with all of its bounds moved down by the given
+    /// delta.
+    ///
+    /// Each `Interval` of the `Selection` is moved as [`Interval::shift_down`]
+    /// moves it, so a bound at the least or greatest value of a [`Finite`]
+    /// type is moved like any other bound.
+    ///
+    /// [`Interval::shift_down`]: ../interval/struct.Interval.html#method.shift_down
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if a moved bound overflows `T` and overflow checks are enabled.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
-    /// let sel = a.union(&b);
+    /// let sel: Selection<i32> = vec![
+    ///     Interval::closed(-3, 5),
+    ///     Interval::closed(10, 12),
+    /// ].into_iter().collect();
     ///
-    /// assert_eq!(sel.enclose(), Interval::closed(-2, 13));
+    /// assert_eq!(sel.shift_down(4).interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(-7, 1),
+    ///     Interval::closed(6, 8),
+    /// ]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn closure(&self) -> Interval<T> {
-        Interval(self.0.closure().normalized())
+    pub fn shift_down<D>(&self, delta: D) -> Self
+        where T: Sub<D, Output=T>, D: Clone
+    {
+        self.interval_iter()
+            .map(|interval| interval.shift_down(delta.clone()))
+            .collect()
+    }
+
+    /// Returns the `Selection` scaled by the given factor around the given
+    /// anchor point, moving each bound point `p` to
+    /// `anchor + (p - anchor) * factor`.
+    ///
+    /// The bounds of each `Interval` are scaled in their normalized form, so
+    /// for [`Finite`] types the closed bounds are scaled. A negative factor
+    /// reverses the `Selection`, and a zero factor collapses any nonempty
+    /// `Selection` to the anchor point.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = vec![
+    ///     Interval::closed(10, 12),
+    ///     Interval::right_open(15, 20),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(sel.scaled(2, 10).interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(10, 14),
+    ///     Interval::closed(20, 28),
+    /// ]);
+    /// assert_eq!(sel.scaled(-1, 10).interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(1, 5),
+    ///     Interval::closed(8, 10),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn scaled<D, F>(&self, factor: F, anchor: T) -> Self
+        where
+            T: Sub<Output=D> + Add<D, Output=T>,
+            D: Mul<F, Output=D>,
+            F: PartialOrd + Default + Clone,
+    {
+        let scale = |p: T| anchor.clone()
+            + (p - anchor.clone()) * factor.clone();
+
+        let reverse = factor < F::default();
+        if factor != F::default() {
+            self.interval_iter()
+                .filter_map(|interval| match (
+                    interval.lower_bound(),
+                    interval.upper_bound())
+                {
+                    (Some(l), Some(u)) if reverse => Some(Interval::new(
+                        u.map(scale),
+                        l.map(scale))),
+                    (Some(l), Some(u))            => Some(Interval::new(
+                        l.map(scale),
+                        u.map(scale))),
+                    _                             => None,
+                })
+                .collect()
+        } else if self.is_empty() {
+            Selection::new()
+        } else {
+            Selection::from(Interval::point(anchor))
+        }
+    }
+
+    /// Returns the `Selection` with each bound point mapped by the given
+    /// strictly increasing function, which may convert to another point type.
+    ///
+    /// The bounds of each `Interval` are mapped in their normalized form, so
+    /// for [`Finite`] types the closed bounds are mapped. Because the function
+    /// is increasing, the mapped `Interval`s remain ordered and disjoint.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the function is observed not to be strictly
+    /// increasing on the bound points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let seconds: Selection<u32> = vec![
+    ///     Interval::closed(0, 5),
+    ///     Interval::closed(10, 15),
+    /// ].into_iter().collect();
+    ///
+    /// let millis: Selection<u64> = seconds
+    ///     .map_monotonic(|s| u64::from(s) * 1000);
+    ///
+    /// assert_eq!(millis.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 5000),
+    ///     Interval::closed(10000, 15000),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn map_monotonic<U, F>(&self, mut f: F) -> Selection<U>
+        where
+            U: IntervalPoint,
+            RawInterval<U>: Normalize,
+            F: FnMut(T) -> U,
+    {
+        let mut prev_upper: Option<U> = None;
+        self.interval_iter()
+            .filter_map(|interval| {
+                let lower = interval.lower_bound()?.map(&mut f);
+                let upper = interval.upper_bound()?.map(&mut f);
+                if cfg!(debug_assertions) {
+                    let (l, u) = (lower.as_ref(), upper.as_ref());
+                    if let (Some(p), Some(l)) = (&prev_upper, l) {
+                        assert!(p < l, "mapping function is not increasing");
+                    }
+                    if let (Some(l), Some(u)) = (l, u) {
+                        assert!(l <= u, "mapping function is not increasing");
+                    }
+                    prev_upper = u.cloned();
+                }
+                Some(Interval::new(lower, upper))
+            })
+            .collect()
     }
 
     // In-place operations
@@ -688,14 +1983,14 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
     /// sel.intersect_in_place(Interval::open(2, 5));
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
     ///     [Interval::open(2, 5)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
@@ -708,14 +2003,14 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
     /// sel.intersect_in_place(Interval::open(2, 5));
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
     ///     [Interval::closed(3, 4)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
@@ -723,6 +2018,37 @@ impl<T> Selection<T>
     /// ```
     pub fn intersect_in_place(&mut self, interval: Interval<T>) {
         self.0.intersect_in_place(&interval.0.denormalized());
+        // Intersecting denormalized intervals may leave intervals with no
+        // points, so they are renormalized to remove them.
+        *self = self.interval_iter().collect();
+    }
+
+    /// Removes all points from the `Selection` which do not lie within the
+    /// given bounding `Interval`. This is the in-place form of
+    /// [`restricted_to`].
+    ///
+    /// [`restricted_to`]: #method.restricted_to
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 2));
+    /// sel.union_in_place(Interval::closed(6, 17));
+    ///
+    /// sel.restrict_to(&Interval::right_open(0, 10));
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 2), Interval::closed(6, 9)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn restrict_to(&mut self, bounds: &Interval<T>) {
+        self.intersect_in_place(bounds.clone());
     }
 
     /// Adds all of the points in the given `Interval` to the `Selection`.
@@ -731,135 +2057,633 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
     /// sel.union_in_place(Interval::open(12, 15));
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
     ///     [Interval::closed(-3, 7), Interval::open(12, 15)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// [`Finite`] types will have their bounds closed:
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 8));
+    /// sel.union_in_place(Interval::open(7, 10));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-2, 9)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        self.0.union_in_place(&interval.0.denormalized());
+    }
+
+    /// Removes all of the points in the given `Interval` from the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.minus_in_place(Interval::open(2, 5));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 2), Interval::closed(5, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.minus_in_place(Interval::closed(2, 5));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 1), Interval::closed(6, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        self.0.minus_in_place(&interval.0.denormalized());
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Patch operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the [`SelectionPatch`] which transforms the `old` `Selection`
+    /// into the `new` `Selection`.
+    ///
+    /// [`SelectionPatch`]: struct.SelectionPatch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let old: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let new: Selection<i32> = Selection::from(Interval::closed(5, 15));
+    ///
+    /// let patch = Selection::diff(&old, &new);
+    /// assert_eq!(patch.added.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(11, 15)]);
+    /// assert_eq!(patch.removed.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn diff(old: &Self, new: &Self) -> SelectionPatch<T> {
+        SelectionPatch {
+            added: new.minus(old),
+            removed: old.minus(new),
+        }
+    }
+
+    /// Applies the given [`SelectionPatch`] to the `Selection`, removing the
+    /// patch's removed points and then adding its added points.
+    ///
+    /// [`SelectionPatch`]: struct.SelectionPatch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let old: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let mut new: Selection<i32> = Selection::from(Interval::closed(5, 15));
+    /// new.union_in_place(Interval::closed(20, 25));
+    ///
+    /// let patch = Selection::diff(&old, &new);
+    /// let mut replica = old.clone();
+    /// replica.apply_patch(&patch);
+    /// assert_eq!(replica, new);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn apply_patch(&mut self, patch: &SelectionPatch<T>) {
+        for interval in patch.removed.interval_iter() {
+            self.minus_in_place(interval);
+        }
+        for interval in patch.added.interval_iter() {
+            self.union_in_place(interval);
+        }
+    }
+
+    /// Unions the given `Interval` into the `Selection`, returning a
+    /// [`SelectionPatch`] of the points which became covered.
+    ///
+    /// [`SelectionPatch`]: struct.SelectionPatch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let patch = sel.union_in_place_with_patch(Interval::closed(5, 15));
+    ///
+    /// assert_eq!(patch.added.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(11, 15)]);
+    /// assert!(patch.removed.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_in_place_with_patch(&mut self, interval: Interval<T>)
+        -> SelectionPatch<T>
+    {
+        let added = Selection::from(interval.clone())
+            .minus(&self.restricted_to(&interval));
+        self.union_in_place(interval);
+        SelectionPatch { added, removed: Selection::new() }
+    }
+
+    /// Intersects the given `Interval` with the `Selection`, returning a
+    /// [`SelectionPatch`] of the points which became uncovered.
+    ///
+    /// [`SelectionPatch`]: struct.SelectionPatch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let patch = sel.intersect_in_place_with_patch(Interval::closed(5, 15));
+    ///
+    /// assert!(patch.added.is_empty());
+    /// assert_eq!(patch.removed.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect_in_place_with_patch(&mut self, interval: Interval<T>)
+        -> SelectionPatch<T>
+    {
+        let removed = self.minus(&Selection::from(interval.clone()));
+        self.intersect_in_place(interval);
+        SelectionPatch { added: Selection::new(), removed }
+    }
+
+    /// Minuses the given `Interval` from the `Selection`, returning a
+    /// [`SelectionPatch`] of the points which became uncovered.
+    ///
+    /// [`SelectionPatch`]: struct.SelectionPatch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let patch = sel.minus_in_place_with_patch(Interval::closed(5, 15));
+    ///
+    /// assert!(patch.added.is_empty());
+    /// assert_eq!(patch.removed.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(5, 10)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus_in_place_with_patch(&mut self, interval: Interval<T>)
+        -> SelectionPatch<T>
+    {
+        let removed = self.restricted_to(&interval);
+        self.minus_in_place(interval);
+        SelectionPatch { added: Selection::new(), removed }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s in the `Selection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter(self.0.interval_iter())
+    }
+
+    /// Returns an iterator over each of the `Interval`s in the `Selection`.
+    pub fn into_interval_iter(self) -> IntoIntervalIter<T> {
+        IntoIntervalIter(self.0.into_iter())
+    }
+}
+
+impl Selection<usize> {
+    /// Returns a mask with one element for each point of the given universe
+    /// `Interval`, in ascending order, which is `true` if the point is in the
+    /// `Selection`. Points of the `Selection` outside of the universe are
+    /// ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the universe contains every `usize`, as the mask length is
+    /// not representable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<usize> = vec![
+    ///     Interval::closed(2, 3),
+    ///     Interval::closed(6, 20),
+    /// ].into_iter().collect();
+    ///
+    /// assert_eq!(sel.to_mask(&Interval::closed(1, 7)),
+    ///     [false, true, true, false, false, true, true]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_mask(&self, universe: &Interval<usize>) -> Vec<bool> {
+        let (start, end) = match (universe.infimum(), universe.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Vec::new(),
+        };
+        let len = (end - start).checked_add(1).expect("mask length overflow");
+
+        let mut mask = vec![false; len];
+        for interval in self.interval_iter() {
+            let interval = interval.intersect(universe);
+            if let (Some(l), Some(u)) = (interval.infimum(), interval.supremum())
+            {
+                mask[l - start..=u - start].fill(true);
+            }
+        }
+        mask
+    }
+
+    /// Constructs a `Selection` from a mask with one element for each point
+    /// of the given universe `Interval`, in ascending order, containing the
+    /// points whose elements are `true`.
+    ///
+    /// # Panics
     ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// Panics if the length of the mask is not the number of points in the
+    /// universe.
+    ///
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 8));
-    /// sel.union_in_place(Interval::open(7, 10));
+    /// let mask = [false, true, true, false, false, true, true];
+    /// let sel = Selection::from_mask(&mask, &Interval::closed(1, 7));
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-2, 9)]);
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(2, 3),
+    ///     Interval::closed(6, 7),
+    /// ]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn union_in_place(&mut self, interval: Interval<T>) {
-        self.0.union_in_place(&interval.0.denormalized());
+    pub fn from_mask(mask: &[bool], universe: &Interval<usize>) -> Self {
+        let start = match (universe.infimum(), universe.supremum()) {
+            (Some(l), Some(u)) => {
+                assert!(mask.len().checked_sub(1) == Some(u - l),
+                    "mask length does not match universe");
+                l
+            },
+            _ => {
+                assert!(mask.is_empty(), "mask length does not match universe");
+                return Selection::new();
+            },
+        };
+
+        let mut intervals = Vec::new();
+        let mut run_start: Option<usize> = None;
+        for (i, &selected) in mask.iter().enumerate() {
+            match (selected, run_start) {
+                (true, None)     => run_start = Some(i),
+                (false, Some(r)) => {
+                    intervals.push(Interval::closed(start + r, start + i - 1));
+                    run_start = None;
+                },
+                _                => (),
+            }
+        }
+        if let Some(r) = run_start {
+            intervals.push(Interval::closed(start + r, start + mask.len() - 1));
+        }
+        intervals.into_iter().collect()
     }
+}
 
-    /// Removes all of the points in the given `Interval` from the `Selection`.
+impl<T> Selection<T> 
+    where 
+        T: IntervalPoint + Finite, 
+{
+    /// Returns an iterator over each of the points in the `Selection`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            intervals: self.0.interval_iter(),
+            front: Interval::empty().iter(),
+            back: Interval::empty().iter(),
+        }
+    }
+
+    /// Returns an iterator which lazily yields each of the points in the
+    /// `Selection` in ascending order. This is equivalent to [`iter`].
+    ///
+    /// [`iter`]: #method.iter
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.minus_in_place(Interval::open(2, 5));
+    /// let sel: Selection<u32> = vec![
+    ///     Interval::closed(3, 5),
+    ///     Interval::closed(9, 10),
+    /// ].into_iter().collect();
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 2), Interval::closed(5, 7)]);
+    /// assert_eq!(sel.points().collect::<Vec<_>>(), [3, 4, 5, 9, 10]);
+    /// assert_eq!(sel.points().rev().next(), Some(10));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    #[inline]
+    pub fn points(&self) -> Iter<'_, T> {
+        self.iter()
+    }
+
+    /// Returns an iterator over each of the points in the `Selection`.
+    pub fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            intervals: self.0.into_iter(),
+            front: Interval::empty().iter(),
+            back: Interval::empty().iter(),
+        }
+    }
+}
+
+impl<T> Default for Selection<T> 
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        Selection::new()
+    }
+}
+
+// Display as a union of intervals. The alternate form uses unicode symbols for
+// the empty set, infinity, and union.
+impl<T> std::fmt::Display for Selection<T>
+    where
+        T: IntervalPoint + std::fmt::Display,
+        RawInterval<T>: Normalize,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let union = if f.alternate() { " ∪ " } else { " U " };
+        let mut intervals = self.interval_iter();
+        match intervals.next() {
+            Some(interval) => interval.fmt(f)?,
+            None           => return Interval::<T>::empty().fmt(f),
+        }
+        for interval in intervals {
+            f.write_str(union)?;
+            interval.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Selection<T>
+    where
+        T: IntervalPoint + std::fmt::Display,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the `Selection` in LaTeX notation, as a union of intervals.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.minus_in_place(Interval::closed(2, 5));
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    /// sel.union_in_place(Interval::point(7));
+    /// assert_eq!(sel.to_latex(), "[0, 4] \\cup \\{7\\}");
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 1), Interval::closed(6, 7)]);
+    /// let sel: Selection<i32> = Selection::empty();
+    /// assert_eq!(sel.to_latex(), "\\emptyset");
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn minus_in_place(&mut self, interval: Interval<T>) {
-        self.0.minus_in_place(&interval.0.denormalized());
+    pub fn to_latex(&self) -> String {
+        let intervals: Vec<_> = self
+            .interval_iter()
+            .map(|interval| interval.to_latex())
+            .collect();
+        if intervals.is_empty() {
+            Interval::<T>::empty().to_latex()
+        } else {
+            intervals.join(" \\cup ")
+        }
     }
+}
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Iterator conversions
-    ////////////////////////////////////////////////////////////////////////////
+// `Selection`s are compared by their normalized intervals, as the same points
+// may be stored in the `TineTree` in more than one way.
+impl<T> PartialEq for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.interval_iter().eq(other.interval_iter())
+    }
+}
 
-    /// Returns an iterator over each of the `Interval`s in the `Selection`.
-    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
-        IntervalIter(self.0.interval_iter())
+impl<T> Eq for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{}
+
+impl<T> Hash for Selection<T>
+    where
+        T: IntervalPoint + Hash,
+        RawInterval<T>: Normalize,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for interval in self.interval_iter() {
+            interval.hash(state);
+        }
     }
+}
 
-    /// Returns an iterator over each of the `Interval`s in the `Selection`.
-    pub fn into_interval_iter(self) -> IntoIntervalIter<T> {
-        IntoIntervalIter(self.0.into_iter())
+////////////////////////////////////////////////////////////////////////////////
+// Assigning operators
+////////////////////////////////////////////////////////////////////////////////
+
+// Union assignment with an `Interval`.
+impl<T> BitOrAssign<Interval<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn bitor_assign(&mut self, rhs: Interval<T>) {
+        self.union_in_place(rhs);
     }
 }
 
-impl<T> Selection<T> 
-    where 
-        T: Ord + Clone + Finite, 
+// Union assignment with a `Selection`.
+impl<T> BitOrAssign<Selection<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
 {
-    /// Returns an iterator over each of the points in the `Selection`.
-    pub fn iter(&self) -> Iter<'_, T> {
-        Iter {
-            intervals: self.0.interval_iter(),
-            current: Interval::empty().iter(),
+    fn bitor_assign(&mut self, rhs: Selection<T>) {
+        for raw in rhs.0.into_iter() {
+            self.0.union_in_place(&raw);
         }
     }
+}
 
-    /// Returns an iterator over each of the points in the `Selection`.
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter {
-            intervals: self.0.into_iter(),
-            current: Interval::empty().iter(),
+// Intersection assignment with an `Interval`.
+impl<T> BitAndAssign<Interval<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn bitand_assign(&mut self, rhs: Interval<T>) {
+        self.intersect_in_place(rhs);
+    }
+}
+
+/// Intersection assignment with a `Selection`.
+///
+/// The intersection is built as a new `Selection` which then replaces this
+/// one, so unlike the other assigning operators this allocates.
+impl<T> BitAndAssign<Selection<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn bitand_assign(&mut self, rhs: Selection<T>) {
+        *self = self.intersect(&rhs);
+    }
+}
+
+// Difference assignment with an `Interval`.
+impl<T> SubAssign<Interval<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn sub_assign(&mut self, rhs: Interval<T>) {
+        self.minus_in_place(rhs);
+    }
+}
+
+// Difference assignment with a `Selection`.
+impl<T> SubAssign<Selection<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn sub_assign(&mut self, rhs: Selection<T>) {
+        for raw in rhs.0.into_iter() {
+            self.0.minus_in_place(&raw);
         }
     }
 }
 
-impl<T> Default for Selection<T> 
+/// Symmetric difference assignment with an `Interval`.
+///
+/// The symmetric difference is built as a new `Selection` which then replaces
+/// this one, so unlike the other assigning operators this allocates.
+impl<T> BitXorAssign<Interval<T>> for Selection<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
-    fn default() -> Self {
-        Selection::new()
+    fn bitxor_assign(&mut self, rhs: Interval<T>) {
+        *self ^= Selection::from(rhs);
+    }
+}
+
+/// Symmetric difference assignment with a `Selection`.
+///
+/// The symmetric difference is built as a new `Selection` which then replaces
+/// this one, so unlike the other assigning operators this allocates.
+impl<T> BitXorAssign<Selection<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn bitxor_assign(&mut self, rhs: Selection<T>) {
+        *self = self.symmetric_difference(&rhs);
     }
 }
 
 impl<T> Extend<Interval<T>> for Selection<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
@@ -872,7 +2696,7 @@ impl<T> Extend<Interval<T>> for Selection<T>
 
 impl<T> From<Interval<T>> for Selection<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     fn from(interval: Interval<T>) -> Self {
@@ -883,7 +2707,7 @@ impl<T> From<Interval<T>> for Selection<T>
 
 impl<T> FromIterator<Interval<T>> for Selection<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
@@ -896,9 +2720,21 @@ impl<T> FromIterator<Interval<T>> for Selection<T>
     }
 }
 
+impl<T> FromIterator<RangeInclusive<T>> for Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item=RangeInclusive<T>>
+    {
+        iter.into_iter().map(Interval::from).collect()
+    }
+}
+
 impl<T> FromIterator<T> for Selection<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
@@ -912,7 +2748,7 @@ impl<T> FromIterator<T> for Selection<T>
 }
 
 impl<T> IntoIterator for Selection<T>
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {
     type Item = T;
     type IntoIter = IntoIter<T>;
@@ -922,6 +2758,152 @@ impl<T> IntoIterator for Selection<T>
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Boundary
+////////////////////////////////////////////////////////////////////////////////
+/// The kind of a boundary event produced by [`Selection::boundary_events`].
+///
+/// [`Selection::boundary_events`]: struct.Selection.html#method.boundary_events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Boundary {
+    /// The sweep enters an `Interval` of the `Selection`.
+    Enter,
+    /// The sweep exits an `Interval` of the `Selection`.
+    Exit,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionStats
+////////////////////////////////////////////////////////////////////////////////
+/// Summary statistics for the `Interval`s of a `Selection`. This `struct` is
+/// created by the [`stats`] method on [`Selection`].
+///
+/// [`stats`]: struct.Selection.html#method.stats
+/// [`Selection`]: struct.Selection.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SelectionStats<W> {
+    /// The number of disjoint `Interval`s.
+    pub count: usize,
+    /// The sum of the widths of the `Interval`s.
+    pub total_width: W,
+    /// The least width of the `Interval`s.
+    pub min_width: Option<W>,
+    /// The greatest width of the `Interval`s.
+    pub max_width: Option<W>,
+    /// The least gap between consecutive `Interval`s.
+    pub min_gap: Option<W>,
+    /// The greatest gap between consecutive `Interval`s.
+    pub max_gap: Option<W>,
+}
+
+impl<W> SelectionStats<W> {
+    /// Returns the mean width of the `Interval`s, or `None` if there are no
+    /// `Interval`s or the count cannot be represented as a width.
+    pub fn mean_width(&self) -> Option<W>
+        where W: Clone + Div<Output=W> + TryFrom<usize>,
+    {
+        if self.count == 0 {
+            return None;
+        }
+        let count = W::try_from(self.count).ok()?;
+        Some(self.total_width.clone() / count)
+    }
+}
+
+/// Returns the `Selection` of the given ordered `Interval`s, with each
+/// `Interval` marked as joined merged with the one following it.
+fn merge_joined<T>(intervals: Vec<Interval<T>>, joined: Vec<bool>)
+    -> Selection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    let mut merged_intervals = Vec::new();
+    let mut current: Option<Interval<T>> = None;
+    for (interval, join) in intervals.into_iter().zip(joined) {
+        let merged = match current.take() {
+            Some(prev) => prev.enclose(&interval),
+            None       => interval,
+        };
+        if join {
+            current = Some(merged);
+        } else {
+            merged_intervals.push(merged);
+        }
+    }
+    merged_intervals.into_iter().collect()
+}
+
+/// Returns the bound on the opposite side of the given bound's point, which
+/// includes the point if the given bound excludes it.
+fn opposite_bound<T>(bound: Bound<T>) -> Bound<T> {
+    match bound {
+        Bound::Include(p) => Bound::Exclude(p),
+        Bound::Exclude(p) => Bound::Include(p),
+        Bound::Infinite   => Bound::Infinite,
+    }
+}
+
+/// Returns the lesser of the given current minimum and value.
+fn min_of<W>(current: Option<W>, value: W) -> W where W: Ord {
+    match current {
+        Some(current) => std::cmp::min(current, value),
+        None          => value,
+    }
+}
+
+/// Returns the greater of the given current maximum and value.
+fn max_of<W>(current: Option<W>, value: W) -> W where W: Ord {
+    match current {
+        Some(current) => std::cmp::max(current, value),
+        None          => value,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionPatch
+////////////////////////////////////////////////////////////////////////////////
+/// The changes between two `Selection`s. This `struct` is created by the
+/// [`diff`] method on [`Selection`], and can be replayed with [`apply_patch`].
+///
+/// [`diff`]: struct.Selection.html#method.diff
+/// [`apply_patch`]: struct.Selection.html#method.apply_patch
+/// [`Selection`]: struct.Selection.html
+#[derive(Debug, Clone)]
+pub struct SelectionPatch<T> {
+    /// The points which were added.
+    pub added: Selection<T>,
+    /// The points which were removed.
+    pub removed: Selection<T>,
+}
+
+impl<T> SelectionPatch<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Returns `true` if the patch makes no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl<T> PartialEq for SelectionPatch<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.added == other.added && self.removed == other.removed
+    }
+}
+
+impl<T> Eq for SelectionPatch<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{}
+
 ////////////////////////////////////////////////////////////////////////////////
 // IntoIntervalIter
 ////////////////////////////////////////////////////////////////////////////////
@@ -931,7 +2913,7 @@ pub struct IntoIntervalIter<T>(crate::tine_tree::IntoIter<T>);
 
 impl<T> Iterator for IntoIntervalIter<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     type Item = Interval<T>;
@@ -946,7 +2928,7 @@ impl<T> Iterator for IntoIntervalIter<T>
 
 impl<T> DoubleEndedIterator for IntoIntervalIter<T>
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
@@ -960,7 +2942,7 @@ impl<T> DoubleEndedIterator for IntoIntervalIter<T>
 
 impl<T> FusedIterator for IntoIntervalIter<T> 
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {}
 
@@ -970,11 +2952,11 @@ impl<T> FusedIterator for IntoIntervalIter<T>
 /// An `Iterator` over the `Interval`s of a `Selection`.
 #[derive(Debug)]
 pub struct IntervalIter<'t, T>(crate::tine_tree::Iter<'t, T>)
-    where T: Ord + Clone;
+    where T: IntervalPoint;
 
 impl<'t, T> Iterator for IntervalIter<'t, T> 
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     type Item = Interval<T>;
@@ -990,7 +2972,7 @@ impl<'t, T> Iterator for IntervalIter<'t, T>
 
 impl<'t, T> DoubleEndedIterator for IntervalIter<'t, T> 
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
@@ -1003,7 +2985,63 @@ impl<'t, T> DoubleEndedIterator for IntervalIter<'t, T>
 
 impl<'t, T> FusedIterator for IntervalIter<'t, T> 
     where
-        T: Ord + Clone,
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntersectionIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of the intersection of two
+/// `Selection`s. Constructed by [`Selection::intersection_iter`].
+///
+/// [`Selection::intersection_iter`]: struct.Selection.html#method.intersection_iter
+#[derive(Debug)]
+pub struct IntersectionIter<'t, T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// The remaining `Interval`s of the first `Selection`.
+    a: Peekable<IntervalIter<'t, T>>,
+    /// The remaining `Interval`s of the second `Selection`.
+    b: Peekable<IntervalIter<'t, T>>,
+}
+
+impl<'t, T> Iterator for IntersectionIter<'t, T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let (Some(a), Some(b)) = (self.a.peek(), self.b.peek()) {
+            let intersection = a.intersect(b);
+
+            // Advance whichever interval ends first, as it cannot overlap
+            // anything further in the other selection. Intervals left empty
+            // by earlier operations have no upper bound and are skipped.
+            let a_upper = a.upper_bound_ref().map(UpperBound);
+            let b_upper = b.upper_bound_ref().map(UpperBound);
+            if a_upper <= b_upper {
+                let _ = self.a.next();
+            } else {
+                let _ = self.b.next();
+            }
+
+            if !intersection.is_empty() {
+                return Some(intersection);
+            }
+        }
+        None
+    }
+}
+
+impl<'t, T> FusedIterator for IntersectionIter<'t, T>
+    where
+        T: IntervalPoint,
         RawInterval<T>: Normalize,
 {}
 
@@ -1015,58 +3053,59 @@ impl<'t, T> FusedIterator for IntervalIter<'t, T>
 #[derive(Debug)]
 pub struct IntoIter<T> 
     where
-        T: Ord + Clone
+        T: IntervalPoint
 {
     intervals: crate::tine_tree::IntoIter<T>,
-    current: crate::interval::Iter<T>,
+    front: crate::interval::Iter<T>,
+    back: crate::interval::Iter<T>,
 }
 
 impl<T> Iterator for IntoIter<T>
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.current.next() {
-            return Some(next);
+        loop {
+            if let Some(next) = self.front.next() {
+                return Some(next);
+            }
+
+            self.front = match self.intervals
+                .next()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.back.next(),
+            };
         }
-
-        self.current = match self.intervals
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next()
     }
 }
 
 impl<T> DoubleEndedIterator for IntoIter<T>
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(next_back) = self.current.next_back() {
-            return Some(next_back);
+        loop {
+            if let Some(next_back) = self.back.next_back() {
+                return Some(next_back);
+            }
+
+            self.back = match self.intervals
+                .next_back()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.front.next_back(),
+            };
         }
-
-        self.current = match self.intervals
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next_back()
     }
 }
 
 impl<T> FusedIterator for IntoIter<T> 
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {}
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -1076,56 +3115,57 @@ impl<T> FusedIterator for IntoIter<T>
 #[derive(Debug)]
 pub struct Iter<'t, T> 
     where
-        T: Ord + Clone + Finite
+        T: IntervalPoint + Finite
 {
     intervals: crate::tine_tree::Iter<'t, T>,
-    current: crate::interval::Iter<T>,
+    front: crate::interval::Iter<T>,
+    back: crate::interval::Iter<T>,
 }
 
 impl<'t, T> Iterator for Iter<'t, T>
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.current.next() {
-            return Some(next);
+        loop {
+            if let Some(next) = self.front.next() {
+                return Some(next);
+            }
+
+            self.front = match self.intervals
+                .next()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.back.next(),
+            };
         }
-
-        self.current = match self.intervals
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next()
     }
 }
 
 impl<'t, T> DoubleEndedIterator for Iter<'t, T>
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(next_back) = self.current.next_back() {
-            return Some(next_back);
+        loop {
+            if let Some(next_back) = self.back.next_back() {
+                return Some(next_back);
+            }
+
+            self.back = match self.intervals
+                .next_back()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.front.next_back(),
+            };
         }
-
-        self.current = match self.intervals
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next_back()
     }
 }
 
 impl<'t, T> FusedIterator for Iter<'t, T>
-    where T: Ord + Clone + Finite,
+    where T: IntervalPoint + Finite,
 {}
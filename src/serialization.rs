@@ -0,0 +1,218 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides alternate serde representations of intervals, for use with the
+//! `#[serde(with = "...")]` field attribute. Requires the `serde` feature.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Notation
+////////////////////////////////////////////////////////////////////////////////
+/// Serializes an [`Interval`] as a string in interval notation, such as
+/// `"[0, 5]"`, and deserializes it from any notation accepted by its
+/// [`FromStr`] implementation.
+///
+/// Because `Interval`s are normalized, the serialized notation may differ from
+/// the notation that was deserialized; `"[0, 5)"` is serialized as `"[0, 4]"`
+/// for integer points.
+///
+/// [`Interval`]: ../../interval/struct.Interval.html
+/// [`FromStr`]: ../../interval/struct.Interval.html#impl-FromStr-for-Interval%3CT%3E
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use serde::Deserialize;
+/// # use serde::Serialize;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "normalize_interval::serialization::notation")]
+///     ports: Interval<u16>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"ports": "[8000, 8080)"}"#)?;
+/// assert_eq!(config.ports, Interval::closed(8000, 8079));
+///
+/// assert_eq!(serde_json::to_string(&config)?,
+///     r#"{"ports":"[8000, 8079]"}"#);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub mod notation {
+    // Local imports.
+    use crate::interval::Interval;
+    use crate::normalize::IntervalPoint;
+    use crate::normalize::Normalize;
+    use crate::raw_interval::RawInterval;
+
+    // External library imports.
+    use serde::de::Error;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    // Standard library imports.
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    /// Serializes the `Interval` as a string in interval notation.
+    pub fn serialize<T, S>(interval: &Interval<T>, serializer: S)
+        -> Result<S::Ok, S::Error>
+        where
+            T: Display,
+            S: Serializer,
+    {
+        serializer.collect_str(interval)
+    }
+
+    /// Deserializes an `Interval` from a string in interval notation.
+    pub fn deserialize<'de, T, D>(deserializer: D)
+        -> Result<Interval<T>, D::Error>
+        where
+            T: IntervalPoint + FromStr,
+            T::Err: Display,
+            RawInterval<T>: Normalize,
+            D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Compact
+////////////////////////////////////////////////////////////////////////////////
+/// Serializes an [`Interval`] as a `(start, end, flags)` tuple, for compact
+/// machine interchange.
+///
+/// The start and end are `None` for unbounded sides. The flags hold
+/// [`INCLUDE_START`] and [`INCLUDE_END`] for included bound points, and
+/// [`EMPTY`] for the empty interval, whose start and end are both `None`.
+///
+/// [`Interval`]: ../../interval/struct.Interval.html
+/// [`INCLUDE_START`]: constant.INCLUDE_START.html
+/// [`INCLUDE_END`]: constant.INCLUDE_END.html
+/// [`EMPTY`]: constant.EMPTY.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use serde::Deserialize;
+/// # use serde::Serialize;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "normalize_interval::serialization::compact")]
+///     span: Interval<u32>,
+///     #[serde(with = "normalize_interval::serialization::compact")]
+///     gap: Interval<u32>,
+/// }
+///
+/// let record = Record {
+///     span: Interval::right_open(10, 20),
+///     gap: Interval::empty(),
+/// };
+/// let json = serde_json::to_string(&record)?;
+/// assert_eq!(json, r#"{"span":[10,19,3],"gap":[null,null,4]}"#);
+///
+/// let record: Record = serde_json::from_str(&json)?;
+/// assert_eq!(record.span, Interval::closed(10, 19));
+/// assert!(record.gap.is_empty());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub mod compact {
+    // Local imports.
+    use crate::bound::Bound;
+    use crate::interval::Interval;
+    use crate::normalize::IntervalPoint;
+    use crate::normalize::Normalize;
+    use crate::raw_interval::RawInterval;
+
+    // External library imports.
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    /// The flag set when the start point is included.
+    pub const INCLUDE_START: u8 = 0b001;
+    /// The flag set when the end point is included.
+    pub const INCLUDE_END: u8 = 0b010;
+    /// The flag set when the interval is empty.
+    pub const EMPTY: u8 = 0b100;
+
+    /// Serializes the `Interval` as a `(start, end, flags)` tuple.
+    pub fn serialize<T, S>(interval: &Interval<T>, serializer: S)
+        -> Result<S::Ok, S::Error>
+        where
+            T: IntervalPoint + Serialize,
+            RawInterval<T>: Normalize,
+            S: Serializer,
+    {
+        let bounds = (interval.lower_bound(), interval.upper_bound());
+        let (lower, upper) = match bounds {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return (None::<T>, None::<T>, EMPTY)
+                .serialize(serializer),
+        };
+
+        let mut flags = 0;
+        if let Bound::Include(_) = lower { flags |= INCLUDE_START; }
+        if let Bound::Include(_) = upper { flags |= INCLUDE_END; }
+        (point(lower), point(upper), flags).serialize(serializer)
+    }
+
+    /// Deserializes an `Interval` from a `(start, end, flags)` tuple.
+    pub fn deserialize<'de, T, D>(deserializer: D)
+        -> Result<Interval<T>, D::Error>
+        where
+            T: IntervalPoint + Deserialize<'de>,
+            RawInterval<T>: Normalize,
+            D: Deserializer<'de>,
+    {
+        let (start, end, flags) =
+            <(Option<T>, Option<T>, u8)>::deserialize(deserializer)?;
+        if flags & EMPTY != 0 {
+            return Ok(Interval::empty());
+        }
+        Ok(Interval::new(
+            bound(start, flags & INCLUDE_START != 0),
+            bound(end, flags & INCLUDE_END != 0)))
+    }
+
+    /// Returns the point of the given `Bound`, if it is finite.
+    fn point<T>(bound: Bound<T>) -> Option<T> {
+        match bound {
+            Bound::Include(p) | Bound::Exclude(p) => Some(p),
+            Bound::Infinite                       => None,
+        }
+    }
+
+    /// Returns the `Bound` for the given point and inclusion flag.
+    fn bound<T>(point: Option<T>, include: bool) -> Bound<T> {
+        match point {
+            Some(p) if include => Bound::Include(p),
+            Some(p)            => Bound::Exclude(p),
+            None               => Bound::Infinite,
+        }
+    }
+}
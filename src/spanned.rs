@@ -0,0 +1,221 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a type pairing an interval with an associated value.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::algorithm::coalesce;
+use crate::bound::LowerBound;
+use crate::bound::UpperBound;
+use crate::interval::Interval;
+use crate::normalize::IntervalPoint;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::cmp::Ordering;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Spanned
+////////////////////////////////////////////////////////////////////////////////
+/// An [`Interval`] paired with a value.
+///
+/// `Spanned` values are ordered by their `Interval`s first, comparing lower
+/// bounds and then upper bounds, and then by their values. Empty `Interval`s
+/// are ordered before all others.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::spanned::Spanned;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut spans = vec![
+///     Spanned::new(Interval::closed(5, 9), "b"),
+///     Spanned::new(Interval::closed(0, 3), "a"),
+/// ];
+/// spans.sort();
+///
+/// assert_eq!(spans[0].value, "a");
+/// assert_eq!(spans[1].interval, Interval::closed(5, 9));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Spanned<T, V> {
+    /// The `Interval` spanned by the value.
+    pub interval: Interval<T>,
+    /// The value associated with the `Interval`.
+    pub value: V,
+}
+
+impl<T, V> Spanned<T, V>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `Spanned` value from the given `Interval` and value.
+    #[inline]
+    pub fn new(interval: Interval<T>, value: V) -> Self {
+        Spanned { interval, value }
+    }
+
+    /// Returns the `Spanned` with the given function applied to its value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::spanned::Spanned;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let span = Spanned::new(Interval::closed(0, 3), 4);
+    /// assert_eq!(span.map(|v| v * 2).value, 8);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Spanned<T, U>
+        where F: FnOnce(V) -> U
+    {
+        Spanned {
+            interval: self.interval,
+            value: f(self.value),
+        }
+    }
+
+    /// Returns the `Spanned` with the given function applied to its
+    /// `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::spanned::Spanned;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let span = Spanned::new(Interval::closed(0, 3), "a");
+    /// assert_eq!(span.map_interval(|i| i.shift(10)).interval,
+    ///     Interval::closed(10, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn map_interval<F>(self, f: F) -> Self
+        where F: FnOnce(Interval<T>) -> Interval<T>
+    {
+        Spanned {
+            interval: f(self.interval),
+            value: self.value,
+        }
+    }
+
+    /// Merges the `Spanned` with another if their `Interval`s overlap or are
+    /// adjacent, combining their values with the given function. Otherwise,
+    /// both `Spanned` values are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns both `Spanned` values if their `Interval`s cannot be merged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::spanned::Spanned;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = Spanned::new(Interval::closed(0, 3), 1);
+    /// let b = Spanned::new(Interval::closed(4, 6), 2);
+    /// let merged = a.merge(b, |x, y| x + y).unwrap();
+    /// assert_eq!(merged, Spanned::new(Interval::closed(0, 6), 3));
+    ///
+    /// let c = Spanned::new(Interval::closed(10, 12), 5);
+    /// assert!(merged.merge(c, |x, y| x + y).is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn merge<F>(self, other: Self, f: F) -> Result<Self, (Self, Self)>
+        where F: FnOnce(V, V) -> V
+    {
+        match coalesce(&self.interval, &other.interval) {
+            Some(interval) => Ok(Spanned {
+                interval,
+                value: f(self.value, other.value),
+            }),
+            None           => Err((self, other)),
+        }
+    }
+
+    /// Returns the bounds of the `Spanned` `Interval` as an orderable key.
+    fn bounds_key(&self) -> Option<(LowerBound<&T>, UpperBound<&T>)> {
+        match (self.interval.lower_bound_ref(), self.interval.upper_bound_ref()) {
+            (Some(l), Some(u)) => Some((LowerBound(l), UpperBound(u))),
+            _                  => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Ordering
+////////////////////////////////////////////////////////////////////////////////
+impl<T, V> PartialOrd for Spanned<T, V>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        V: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, V> Ord for Spanned<T, V>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+        V: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bounds_key()
+            .cmp(&other.bounds_key())
+            .then_with(|| self.value.cmp(&other.value))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+impl<T, V> From<(Interval<T>, V)> for Spanned<T, V>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(pair: (Interval<T>, V)) -> Self {
+        Spanned::new(pair.0, pair.1)
+    }
+}
+
+impl<T, V> From<Spanned<T, V>> for (Interval<T>, V) {
+    fn from(spanned: Spanned<T, V>) -> Self {
+        (spanned.interval, spanned.value)
+    }
+}
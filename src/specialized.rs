@@ -0,0 +1,238 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides lightweight interval types with a fixed bound style.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::IntervalPoint;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::ops::Range;
+use std::ops::RangeInclusive;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ClosedInterval
+////////////////////////////////////////////////////////////////////////////////
+/// An interval which includes both of its bound points.
+///
+/// Unlike [`Interval`], a `ClosedInterval` is not normalized and carries no
+/// bound style information, so its operations reduce to plain comparisons of
+/// its points. A `ClosedInterval` whose lower point is greater than its upper
+/// point is empty.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::specialized::ClosedInterval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = ClosedInterval::new(0, 10);
+/// let b = ClosedInterval::new(10, 20);
+///
+/// assert!(a.contains(&10));
+/// assert!(a.intersects(&b));
+/// assert_eq!(a.intersect(&b), ClosedInterval::new(10, 10));
+/// assert_eq!(Interval::from(a), Interval::closed(0, 10));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClosedInterval<T> {
+    /// The lower bound point.
+    lower: T,
+    /// The upper bound point.
+    upper: T,
+}
+
+impl<T> ClosedInterval<T> where T: Ord + Clone {
+    /// Constructs a new `ClosedInterval` between the given points.
+    #[inline]
+    pub fn new(lower: T, upper: T) -> Self {
+        ClosedInterval { lower, upper }
+    }
+
+    /// Returns the lower bound point of the `ClosedInterval`.
+    #[inline]
+    pub fn lower(&self) -> &T {
+        &self.lower
+    }
+
+    /// Returns the upper bound point of the `ClosedInterval`.
+    #[inline]
+    pub fn upper(&self) -> &T {
+        &self.upper
+    }
+
+    /// Returns `true` if the `ClosedInterval` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.lower > self.upper
+    }
+
+    /// Returns `true` if the `ClosedInterval` contains the given point.
+    #[inline]
+    pub fn contains(&self, point: &T) -> bool {
+        self.lower <= *point && *point <= self.upper
+    }
+
+    /// Returns `true` if the `ClosedInterval`s share any points.
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.lower <= other.upper
+            && other.lower <= self.upper
+    }
+
+    /// Returns the `ClosedInterval` of points shared by both
+    /// `ClosedInterval`s.
+    #[inline]
+    pub fn intersect(&self, other: &Self) -> Self {
+        ClosedInterval {
+            lower: std::cmp::max(&self.lower, &other.lower).clone(),
+            upper: std::cmp::min(&self.upper, &other.upper).clone(),
+        }
+    }
+}
+
+impl<T> From<ClosedInterval<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(interval: ClosedInterval<T>) -> Self {
+        Interval::closed(interval.lower, interval.upper)
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for ClosedInterval<T> where T: Ord + Clone {
+    fn from(r: RangeInclusive<T>) -> Self {
+        let (lower, upper) = r.into_inner();
+        ClosedInterval::new(lower, upper)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RightOpenInterval
+////////////////////////////////////////////////////////////////////////////////
+/// An interval which includes its lower bound point and excludes its upper
+/// bound point.
+///
+/// Unlike [`Interval`], a `RightOpenInterval` is not normalized and carries no
+/// bound style information, so its operations reduce to plain comparisons of
+/// its points. A `RightOpenInterval` whose lower point is not less than its
+/// upper point is empty.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::specialized::RightOpenInterval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = RightOpenInterval::new(0, 10);
+/// let b = RightOpenInterval::new(10, 20);
+///
+/// assert!(!a.contains(&10));
+/// assert!(!a.intersects(&b));
+/// assert!(a.intersect(&b).is_empty());
+/// assert_eq!(Interval::from(a), Interval::closed(0, 9));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RightOpenInterval<T> {
+    /// The included lower bound point.
+    lower: T,
+    /// The excluded upper bound point.
+    upper: T,
+}
+
+impl<T> RightOpenInterval<T> where T: Ord + Clone {
+    /// Constructs a new `RightOpenInterval` between the given points.
+    #[inline]
+    pub fn new(lower: T, upper: T) -> Self {
+        RightOpenInterval { lower, upper }
+    }
+
+    /// Returns the included lower bound point of the `RightOpenInterval`.
+    #[inline]
+    pub fn lower(&self) -> &T {
+        &self.lower
+    }
+
+    /// Returns the excluded upper bound point of the `RightOpenInterval`.
+    #[inline]
+    pub fn upper(&self) -> &T {
+        &self.upper
+    }
+
+    /// Returns `true` if the `RightOpenInterval` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.lower >= self.upper
+    }
+
+    /// Returns `true` if the `RightOpenInterval` contains the given point.
+    #[inline]
+    pub fn contains(&self, point: &T) -> bool {
+        self.lower <= *point && *point < self.upper
+    }
+
+    /// Returns `true` if the `RightOpenInterval`s share any points.
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.lower < other.upper
+            && other.lower < self.upper
+    }
+
+    /// Returns the `RightOpenInterval` of points shared by both
+    /// `RightOpenInterval`s.
+    #[inline]
+    pub fn intersect(&self, other: &Self) -> Self {
+        RightOpenInterval {
+            lower: std::cmp::max(&self.lower, &other.lower).clone(),
+            upper: std::cmp::min(&self.upper, &other.upper).clone(),
+        }
+    }
+}
+
+impl<T> From<RightOpenInterval<T>> for Interval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn from(interval: RightOpenInterval<T>) -> Self {
+        Interval::right_open(interval.lower, interval.upper)
+    }
+}
+
+impl<T> From<Range<T>> for RightOpenInterval<T> where T: Ord + Clone {
+    fn from(r: Range<T>) -> Self {
+        RightOpenInterval::new(r.start, r.end)
+    }
+}
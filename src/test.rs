@@ -39,5 +39,13 @@ macro_rules! assert_eq_i {
 }
 
 // Module declarations.
+mod arithmetic;
+#[cfg(feature = "chrono")]
+mod calendar;
+mod compact;
+mod edit;
+mod float;
+mod interval;
 mod raw_interval;
+mod selection;
 mod tine_tree;
@@ -0,0 +1,145 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for interval arithmetic.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::arithmetic::next_down;
+use crate::arithmetic::next_up;
+use crate::float::TotalF64;
+use crate::interval::Interval;
+
+
+/// Constructs a closed `Interval` of `TotalF64` points.
+fn closed(lower: f64, upper: f64) -> Interval<TotalF64> {
+    Interval::closed(TotalF64(lower), TotalF64(upper))
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Rounding tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn next_up_and_down() {
+    let tiny = f64::from_bits(1);
+    assert_eq!(next_up(0.0), tiny);
+    assert_eq!(next_up(-0.0), tiny);
+    assert_eq!(next_down(0.0), -tiny);
+    assert_eq!(next_up(-tiny).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(next_up(1.0), 1.0 + f64::EPSILON);
+    assert_eq!(next_down(1.0), 1.0 - f64::EPSILON / 2.0);
+    assert_eq!(next_up(-1.0), -1.0 + f64::EPSILON / 2.0);
+    assert_eq!(next_up(f64::MAX), f64::INFINITY);
+    assert_eq!(next_up(f64::INFINITY), f64::INFINITY);
+    assert_eq!(next_up(f64::NEG_INFINITY), f64::MIN);
+    assert_eq!(next_down(f64::MIN), f64::NEG_INFINITY);
+    assert!(next_up(f64::NAN).is_nan());
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Division tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn div_without_zero_encloses_quotients() {
+    let q = closed(1.0, 6.0).div_extended(&closed(2.0, 3.0));
+    assert_eq!(q.interval_count(), 1);
+    assert!(q.contains(&TotalF64(1.0 / 3.0)));
+    assert!(q.contains(&TotalF64(3.0)));
+    assert!(!q.contains(&TotalF64(3.1)));
+}
+
+#[test]
+fn div_by_interval_ending_at_zero() {
+    let q = closed(1.0, 2.0).div_extended(&closed(0.0, 4.0));
+    assert_eq!(q.interval_count(), 1);
+    assert!(q.contains(&TotalF64(0.25)));
+    assert!(q.contains(&TotalF64(f64::INFINITY)));
+    assert!(!q.contains(&TotalF64(0.2)));
+
+    let q = closed(-2.0, -1.0).div_extended(&closed(0.0, 4.0));
+    assert!(q.contains(&TotalF64(f64::NEG_INFINITY)));
+    assert!(q.contains(&TotalF64(-0.25)));
+    assert!(!q.contains(&TotalF64(0.0)));
+}
+
+#[test]
+fn div_negative_by_interval_containing_zero() {
+    let q = closed(-2.0, -1.0).div_extended(&closed(-1.0, 4.0));
+    assert_eq!(q.interval_count(), 2);
+    assert!(q.contains(&TotalF64(-0.25)));
+    assert!(!q.contains(&TotalF64(-0.2)));
+    assert!(q.contains(&TotalF64(1.0)));
+    assert!(!q.contains(&TotalF64(0.9)));
+}
+
+#[test]
+fn div_with_zero_in_both() {
+    let q = closed(-1.0, 1.0).div_extended(&closed(-1.0, 1.0));
+    assert!(q.contains(&TotalF64(f64::NEG_INFINITY)));
+    assert!(q.contains(&TotalF64(f64::INFINITY)));
+    assert_eq!(q.interval_count(), 1);
+}
+
+#[test]
+fn div_by_zero_is_empty() {
+    assert!(closed(1.0, 2.0).div_extended(&closed(-0.0, 0.0)).is_empty());
+    assert!(closed(1.0, 2.0).div_extended(&Interval::empty()).is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Bisection tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn bisect_at_midpoint() {
+    let (lower, upper) = closed(-1.0, 3.0).bisect();
+    assert_eq!(lower, closed(-1.0, 1.0));
+    assert_eq!(upper, closed(next_up(1.0), 3.0));
+
+    let one = closed(1.0, 1.0);
+    assert_eq!(one.bisect(), (one, Interval::empty()));
+
+    let adjacent = closed(1.0, next_up(1.0));
+    assert_eq!(adjacent.bisect(),
+        (closed(1.0, 1.0), closed(next_up(1.0), next_up(1.0))));
+}
+
+#[test]
+fn bisect_unbounded() {
+    let (lower, upper) = closed(f64::NEG_INFINITY, f64::INFINITY).bisect();
+    assert_eq!(lower, closed(f64::NEG_INFINITY, -0.0));
+    assert_eq!(upper, closed(0.0, f64::INFINITY));
+
+    let full: Interval<TotalF64> = Interval::full();
+    let (lower, upper) = full.bisect();
+    assert!(lower.contains(&TotalF64(-1.0)));
+    assert!(upper.contains(&TotalF64(1.0)));
+
+    let found = full.bisect_until(|lower| {
+        lower.supremum().map_or(false, |x| x.get() >= 1e300)
+    });
+    assert_eq!(found, Interval::point(TotalF64(1e300)));
+}
+
+#[test]
+fn refine_at_midpoints() {
+    let leaves = closed(0.0, 1.0).refine(2, |_| true);
+    assert_eq!(leaves, [
+        closed(0.0, 0.25),
+        closed(next_up(0.25), 0.5),
+        closed(next_up(0.5), 0.75),
+        closed(next_up(0.75), 1.0),
+    ]);
+}
@@ -0,0 +1,120 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for calendar splitting.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+
+// External library imports.
+use chrono::FixedOffset;
+use chrono::LocalResult;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+
+
+const HOUR: i64 = 3600;
+const DAY: i64 = 24 * HOUR;
+
+/// A time zone at UTC which springs forward an hour at local midnight on
+/// 1970-01-02 and falls back an hour at local midnight on 1970-01-04, so that
+/// the first midnight is skipped and the second is repeated.
+#[derive(Debug, Clone, Copy)]
+struct Transitions;
+
+impl Transitions {
+    /// Returns the offset in seconds in effect at the given Unix timestamp.
+    fn offset_at(timestamp: i64) -> i32 {
+        if (DAY..3 * DAY).contains(&timestamp) { HOUR as i32 } else { 0 }
+    }
+}
+
+impl TimeZone for Transitions {
+    type Offset = FixedOffset;
+
+    fn from_offset(_offset: &FixedOffset) -> Self {
+        Transitions
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate)
+        -> LocalResult<FixedOffset>
+    {
+        self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime)
+        -> LocalResult<FixedOffset>
+    {
+        // Each offset is valid if it maps the local time to an instant at
+        // which that offset is in effect. The larger offset gives the earlier
+        // instant.
+        let local = local.and_utc().timestamp();
+        let valid = [HOUR as i32, 0]
+            .iter()
+            .copied()
+            .filter(|&offset| {
+                Transitions::offset_at(local - i64::from(offset)) == offset
+            })
+            .map(|offset| FixedOffset::east_opt(offset).unwrap())
+            .collect::<Vec<_>>();
+        match valid[..] {
+            []               => LocalResult::None,
+            [offset]         => LocalResult::Single(offset),
+            [earlier, later] => LocalResult::Ambiguous(earlier, later),
+            _                => unreachable!("at most two offsets"),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        let offset = Transitions::offset_at(utc.and_utc().timestamp());
+        FixedOffset::east_opt(offset).unwrap()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Day splitting tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn split_by_day_across_transitions() {
+    let span: Interval<i64> = Interval::closed(0, 5 * DAY - 1);
+
+    assert_eq!(span.split_by_day(&Transitions), [
+        // 1970-01-01, 24 hours.
+        Interval::closed(0, DAY - 1),
+        // 1970-01-02 begins at 01:00 after the skipped midnight, 23 hours.
+        Interval::closed(DAY, 2 * DAY - HOUR - 1),
+        // 1970-01-03, 24 hours.
+        Interval::closed(2 * DAY - HOUR, 3 * DAY - HOUR - 1),
+        // 1970-01-04 begins at the earlier of the repeated midnights, 25 hours.
+        Interval::closed(3 * DAY - HOUR, 4 * DAY - 1),
+        // 1970-01-05, 24 hours.
+        Interval::closed(4 * DAY, 5 * DAY - 1),
+    ]);
+}
+
+#[test]
+fn split_by_day_within_transition_days() {
+    // From 1970-01-02T06:00 local to 1970-01-04T00:30 local, the second time.
+    let span: Interval<i64> = Interval::closed(DAY + 5 * HOUR, 3 * DAY + 1800);
+
+    assert_eq!(span.split_by_day(&Transitions), [
+        Interval::closed(DAY + 5 * HOUR, 2 * DAY - HOUR - 1),
+        Interval::closed(2 * DAY - HOUR, 3 * DAY - HOUR - 1),
+        Interval::closed(3 * DAY - HOUR, 3 * DAY + 1800),
+    ]);
+}
@@ -0,0 +1,87 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`CompactSelection`].
+//!
+//! [`CompactSelection`] struct.CompactSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::compact::CompactSelection;
+use crate::compact::DenseSelection;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn contains_matches_selection() {
+    let sel: Selection<i32> = vec![
+        Interval::closed(-20, -15),
+        Interval::point(0),
+        Interval::closed(3, 8),
+        Interval::closed(40, 41),
+    ].into_iter().collect();
+    let compact = CompactSelection::from(&sel);
+
+    for p in -25..45 {
+        assert_eq!(compact.contains(&p), sel.contains(&p), "point {}", p);
+    }
+}
+
+#[test]
+fn full_round_trip() {
+    let sel: Selection<u8> = Selection::from(Interval::full());
+    let compact = CompactSelection::from(&sel);
+    assert!(compact.contains(&0));
+    assert!(compact.contains(&255));
+    assert_eq!(compact.to_selection(), sel);
+}
+
+#[test]
+fn dense_round_trip() {
+    let sel: Selection<u32> = vec![
+        Interval::closed(3, 3),
+        Interval::closed(60, 70),
+        Interval::closed(127, 128),
+        Interval::closed(130, 320),
+        Interval::closed(384, 447),
+    ].into_iter().collect();
+    let dense = DenseSelection::from(&sel);
+
+    for p in 0..500 {
+        assert_eq!(dense.contains(&p), sel.contains(&p), "point {}", p);
+    }
+    assert_eq!(dense.to_selection(), sel);
+    assert_eq!(DenseSelection::from(&Selection::new()).to_selection(),
+        Selection::new());
+}
+
+#[test]
+fn dense_max_span() {
+    let sel: Selection<u32> = Selection::from(Interval::closed(10, 19));
+    assert_eq!(DenseSelection::with_max_span(&sel, 10),
+        Some(DenseSelection::from(&sel)));
+    assert_eq!(DenseSelection::with_max_span(&sel, 9), None);
+    assert_eq!(DenseSelection::with_max_span(&Selection::new(), 0),
+        Some(DenseSelection::default()));
+
+    let full: Selection<u32> = Selection::from(Interval::full());
+    assert_eq!(DenseSelection::with_max_span(&full, u64::from(u32::MAX)),
+        None);
+
+    let sel: Selection<u32> = Selection::from(Interval::closed(0, 9));
+    let dense = DenseSelection::with_max_span(&sel, 10).unwrap();
+    assert_eq!(dense.point_count(), 10);
+    assert_eq!(dense.to_selection(), sel);
+}
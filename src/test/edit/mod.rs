@@ -0,0 +1,44 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for offset remapping.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::edit::InsertPolicy;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////
+// Insertion tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn remap_insert_drop() {
+    let sel: Selection<usize> = vec![
+        Interval::closed(0, 4),
+        Interval::closed(10, 14),
+        Interval::closed(20, 24),
+    ].into_iter().collect();
+
+    let sel = sel.remap_insert(12, 5, InsertPolicy::Drop);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 4),
+        Interval::closed(25, 29),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "remapped offset overflow")]
+fn remap_insert_overflow() {
+    let span: Interval<usize> = Interval::closed(10, usize::MAX - 1);
+    let _ = span.remap_insert(5, 2, InsertPolicy::Expand);
+}
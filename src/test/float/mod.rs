@@ -0,0 +1,81 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the total float wrappers.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::float::TotalF32;
+use crate::float::TotalF64;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////
+// Stepping tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn succ_crosses_zero() {
+    let neg_tiny = TotalF64(-f64::from_bits(1));
+    assert_eq!(neg_tiny.succ(), Some(TotalF64(-0.0)));
+    assert_eq!(TotalF64(-0.0).succ(), Some(TotalF64(0.0)));
+    assert_eq!(TotalF64(0.0).succ(), Some(TotalF64(f64::from_bits(1))));
+    assert_eq!(TotalF64(0.0).pred(), Some(TotalF64(-0.0)));
+}
+
+#[test]
+fn succ_matches_next_up() {
+    for &x in &[-1.5f64, -f64::MIN_POSITIVE, 1.0, 3.25e100, f64::MAX] {
+        assert_eq!(TotalF64(x).succ(), Some(TotalF64(x.next_up())));
+        assert_eq!(TotalF64(x).pred(), Some(TotalF64(x.next_down())));
+    }
+    for &x in &[-1.5f32, 1.0, f32::MAX] {
+        assert_eq!(TotalF32(x).succ(), Some(TotalF32(x.next_up())));
+    }
+}
+
+#[test]
+fn extremes_are_nan() {
+    assert!(TotalF64::MINIMUM.get().is_nan());
+    assert!(TotalF64::MAXIMUM.get().is_nan());
+    assert!(TotalF64::MINIMUM.get().is_sign_negative());
+    assert_eq!(TotalF64::MINIMUM.pred(), None);
+    assert_eq!(TotalF64::MAXIMUM.succ(), None);
+    assert!(TotalF64(f64::INFINITY).succ().unwrap().get().is_nan());
+    assert!(TotalF32::MAXIMUM.get().is_nan());
+}
+
+#[test]
+fn steps_between_counts_representable_values() {
+    let one = TotalF64(1.0);
+    assert_eq!(TotalF64::steps_between(&one, &TotalF64(1.0f64.next_up())),
+        Some(1));
+    assert_eq!(TotalF64::steps_between(&TotalF64(-0.0), &TotalF64(0.0)),
+        Some(1));
+    assert_eq!(TotalF64::steps_between(&TotalF64(1.0), &TotalF64(0.0)), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Set tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn selection_is_deterministic_with_nan() {
+    let mut a: Selection<TotalF64> = Selection::new();
+    a.union_in_place(Interval::closed(TotalF64(0.0), TotalF64(f64::NAN)));
+    a.union_in_place(Interval::closed(TotalF64(-1.0), TotalF64(-0.0)));
+
+    assert!(a.contains(&TotalF64(f64::INFINITY)));
+    assert!(a.contains(&TotalF64(f64::NAN)));
+    assert_eq!(a.interval_count(), 1);
+}
@@ -0,0 +1,292 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Interval`].
+//!
+//! [`Interval`] struct.Interval.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::algorithm::find_containing;
+use crate::algorithm::histogram;
+use crate::algorithm::PartitionErrorKind;
+use crate::algorithm::verify_partition;
+use crate::bed::write_bed;
+use crate::bound::Bound;
+use crate::error::IntervalError;
+use crate::interval::Interval;
+use crate::normalize::CheckedAdd;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::error::Error;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+
+/// Returns the hash of the given value.
+fn hash_of<H: Hash>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns `Interval`s built from every combination of bounds on the given
+/// points.
+fn all_intervals(points: &[i8]) -> Vec<Interval<i8>> {
+    let bounds = |p: i8| vec![
+        Bound::Include(p),
+        Bound::Exclude(p),
+        Bound::Infinite,
+    ];
+    let mut intervals = Vec::new();
+    for &l in points {
+        for &u in points {
+            for lower in bounds(l) {
+                for upper in bounds(u) {
+                    intervals.push(Interval::new(lower, upper));
+                }
+            }
+        }
+    }
+    intervals
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Equality tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn eq_and_hash_follow_points() {
+    let intervals = all_intervals(&[i8::MIN, -1, 0, 1, i8::MAX]);
+    for a in &intervals {
+        for b in &intervals {
+            let same_points = a.iter().eq(b.iter());
+            assert_eq!(a == b, same_points, "{:?} {:?}", a, b);
+            if same_points {
+                assert_eq!(hash_of(a), hash_of(b), "{:?} {:?}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn eq_std_ranges() {
+    assert_eq!(Interval::closed(0, 4), 0..5);
+    assert_eq!(Interval::closed(0, 4), 0..=4);
+    assert_eq!(0..5, Interval::open(-1, 5));
+    assert_eq!(0..=4, Interval::right_open(0, 5));
+    assert_ne!(Interval::closed(0, 5), 0..5);
+
+    assert_eq!(Interval::<i32>::empty(), 3..3);
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 5..=2;
+    assert_eq!(Interval::<i32>::empty(), reversed);
+}
+
+#[test]
+fn selection_eq_and_hash_follow_points() {
+    let intervals = all_intervals(&[-2, 0, 2]);
+    for a in &intervals {
+        for b in &intervals {
+            let a = Selection::from(*a);
+            let b = Selection::from(*b);
+            let same_points = a.iter().eq(b.iter());
+            assert_eq!(a == b, same_points);
+            if same_points {
+                assert_eq!(hash_of(&a), hash_of(&b));
+            }
+        }
+    }
+}
+
+#[test]
+fn bins_near_maximum() {
+    let interval: Interval<u8> = Interval::closed(250, 255);
+    assert_eq!(interval.bins(10).collect::<Vec<_>>(), [
+        Interval::closed(250, 255)]);
+
+    let interval: Interval<u8> = Interval::closed(236, 255);
+    assert_eq!(interval.bins(10).collect::<Vec<_>>(), [
+        Interval::closed(236, 245),
+        Interval::closed(246, 255),
+    ]);
+
+    let interval: Interval<u8> = Interval::closed(0, 255);
+    assert_eq!(interval.bins(128).collect::<Vec<_>>(), [
+        Interval::closed(0, 127),
+        Interval::closed(128, 255),
+    ]);
+}
+
+#[test]
+fn bins_distinct_width_type() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Tick(u16);
+
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+    struct Span(u16);
+
+    impl Finite for Tick {
+        const MINIMUM: Tick = Tick(u16::MIN);
+        const MAXIMUM: Tick = Tick(u16::MAX);
+        fn pred(&self) -> Option<Tick> { self.0.checked_sub(1).map(Tick) }
+        fn succ(&self) -> Option<Tick> { self.0.checked_add(1).map(Tick) }
+    }
+
+    impl CheckedAdd<Span> for Tick {
+        fn checked_add(&self, offset: &Span) -> Option<Tick> {
+            self.0.checked_add(offset.0).map(Tick)
+        }
+    }
+
+    let interval = Interval::closed(Tick(65530), Tick(u16::MAX));
+    assert_eq!(interval.bins(Span(4)).collect::<Vec<_>>(), [
+        Interval::closed(Tick(65530), Tick(65533)),
+        Interval::closed(Tick(65534), Tick(u16::MAX)),
+    ]);
+}
+
+#[test]
+fn overlap_fraction_extreme_bounds() {
+    let full: Interval<i128> = Interval::full();
+    assert_eq!(full.overlap_fraction(&full), 1.0);
+    assert_eq!(full.overlap_fraction(&Interval::closed(0, i128::MAX)), 0.5);
+    assert_eq!(Interval::point(i128::MIN).overlap_fraction(&full), 1.0);
+
+    let full: Interval<u128> = Interval::full();
+    let top: Interval<u128> = Interval::closed(u128::MAX - 9, u128::MAX);
+    assert_eq!(full.overlap_fraction(&Interval::closed(1 << 127, u128::MAX)),
+        0.5);
+    assert_eq!(top.overlap_fraction(&Interval::closed(u128::MAX - 4,
+        u128::MAX)), 0.5);
+    assert_eq!(top.overlap_fraction(&Interval::closed(0, 10)), 0.0);
+}
+
+#[test]
+fn split_at_fractions_extreme_bounds() {
+    let full: Interval<i128> = Interval::full();
+    assert_eq!(full.split_at_fractions(&[0.5]), [
+        Interval::closed(i128::MIN, -1),
+        Interval::closed(0, i128::MAX),
+    ]);
+
+    let full: Interval<u128> = Interval::full();
+    assert_eq!(full.split_at_fractions(&[0.0, 0.5, 1.0]), [
+        Interval::empty(),
+        Interval::closed(0, (1 << 127) - 1),
+        Interval::closed(1 << 127, u128::MAX),
+        Interval::empty(),
+    ]);
+
+    let top: Interval<u128> = Interval::closed(u128::MAX - 9, u128::MAX);
+    assert_eq!(top.split_at_fractions(&[0.7]), [
+        Interval::closed(u128::MAX - 9, u128::MAX - 3),
+        Interval::closed(u128::MAX - 2, u128::MAX),
+    ]);
+}
+
+#[test]
+fn log_bins_extreme_bounds() {
+    let interval: Interval<u128> = Interval::closed(1, u128::MAX);
+    let bins = interval.log_bins(4);
+    assert_eq!(bins[0], Interval::closed(1, (1 << 32) - 1));
+    assert_eq!(bins[3].supremum(), Some(u128::MAX));
+    assert_eq!(Interval::union_all(bins), Selection::from(interval));
+
+    let interval: Interval<i128> = Interval::closed(1, i128::MAX);
+    let bins = interval.log_bins(2);
+    assert_eq!(bins[1].supremum(), Some(i128::MAX));
+    assert_eq!(Interval::union_all(bins), Selection::from(interval));
+}
+
+#[test]
+fn bisect_extreme_bounds() {
+    let full: Interval<i128> = Interval::full();
+    assert_eq!(full.bisect(), (
+        Interval::closed(i128::MIN, -1),
+        Interval::closed(0, i128::MAX)));
+
+    let full: Interval<u128> = Interval::full();
+    assert_eq!(full.bisect(), (
+        Interval::closed(0, (1 << 127) - 1),
+        Interval::closed(1 << 127, u128::MAX)));
+    assert_eq!(full.bisect_until(|lower| lower.contains(&u128::MAX)),
+        Interval::point(u128::MAX));
+}
+
+#[test]
+fn refine_extreme_bounds() {
+    let full: Interval<u128> = Interval::full();
+    let leaves = full.refine(3, |sub| sub.contains(&u128::MAX));
+    assert_eq!(leaves.len(), 4);
+    assert_eq!(leaves[0], Interval::closed(0, (1 << 127) - 1));
+    assert_eq!(leaves[3], Interval::closed(7 << 125, u128::MAX));
+    assert_eq!(Interval::union_all(leaves), Selection::from(full));
+
+    let full: Interval<i128> = Interval::full();
+    let leaves = full.refine(2, |sub| sub.contains(&i128::MIN));
+    assert_eq!(leaves, [
+        Interval::closed(i128::MIN, i128::MIN / 2 - 1),
+        Interval::closed(i128::MIN / 2, -1),
+        Interval::closed(0, i128::MAX),
+    ]);
+}
+
+#[test]
+fn cmp_point_empty_interval() {
+    assert_eq!(Interval::<i32>::empty().cmp_point(&0), Ordering::Less);
+
+    let sorted = [
+        Interval::empty(),
+        Interval::closed(0, 4),
+        Interval::closed(10, 14),
+    ];
+    assert_eq!(find_containing(&sorted, &2), Some(1));
+    assert_eq!(find_containing(&sorted, &-2), None);
+    assert_eq!(histogram(&sorted, vec![-2, 0, 3, 12]), [0, 2, 1]);
+}
+
+#[test]
+fn interval_error_conversions() {
+    fn check_partition() -> Result<(), IntervalError> {
+        let target: Interval<i32> = Interval::closed(0, 9);
+        verify_partition(&target, vec![Interval::closed(0, 4)])?;
+        Ok(())
+    }
+    let e = check_partition().unwrap_err();
+    assert!(matches!(e, IntervalError::Partition(PartitionErrorKind::Gap)));
+    assert_eq!(e.to_string(), "target portion is not covered");
+    assert!(e.source().is_none());
+
+    let overlap = verify_partition(
+        &Interval::closed(0, 9),
+        vec![Interval::closed(0, 5), Interval::closed(5, 9)]);
+    assert_eq!(
+        overlap.map_err(|e| e.kind()),
+        Err(PartitionErrorKind::Overlap));
+
+    fn write_empty() -> Result<(), IntervalError> {
+        write_bed(Vec::new(), vec![("chr1", Interval::empty())])?;
+        Ok(())
+    }
+    assert!(matches!(write_empty(), Err(IntervalError::Bed(_))));
+
+    fn validate_both() -> Result<(), IntervalError> {
+        Interval::closed(0, 9).validate()?;
+        Selection::from(Interval::closed(0, 9)).validate()?;
+        Ok(())
+    }
+    assert!(validate_both().is_ok());
+}
@@ -84,6 +84,46 @@ fn closed_reordering() {
     assert_eq!(RawInterval::closed(4, 2), Empty);
 }
 
+////////////////////////////////////////////////////////////////////////////
+// Conversion tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn as_ref() {
+    let a: RawInterval<i32> = Empty;
+    assert_eq!(a.as_ref(), Empty);
+
+    let a: RawInterval<i32> = Point(3);
+    assert_eq!(a.as_ref(), Point(&3));
+
+    let a: RawInterval<i32> = Open(0, 3);
+    assert_eq!(a.as_ref(), Open(&0, &3));
+
+    let a: RawInterval<i32> = LeftOpen(0, 3);
+    assert_eq!(a.as_ref(), LeftOpen(&0, &3));
+
+    let a: RawInterval<i32> = RightOpen(0, 3);
+    assert_eq!(a.as_ref(), RightOpen(&0, &3));
+
+    let a: RawInterval<i32> = Closed(0, 3);
+    assert_eq!(a.as_ref(), Closed(&0, &3));
+
+    let a: RawInterval<i32> = UpTo(3);
+    assert_eq!(a.as_ref(), UpTo(&3));
+
+    let a: RawInterval<i32> = UpFrom(3);
+    assert_eq!(a.as_ref(), UpFrom(&3));
+
+    let a: RawInterval<i32> = To(3);
+    assert_eq!(a.as_ref(), To(&3));
+
+    let a: RawInterval<i32> = From(3);
+    assert_eq!(a.as_ref(), From(&3));
+
+    let a: RawInterval<i32> = Full;
+    assert_eq!(a.as_ref(), Full);
+}
+
 ////////////////////////////////////////////////////////////////////////////
 // Bound accessor tests
 ////////////////////////////////////////////////////////////////////////////
@@ -353,6 +393,126 @@ fn contains() {
     assert!(a.contains(&4));
 }
 
+#[test]
+fn contains_by() {
+    let intervals: [RawInterval<i32>; 11] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), UpTo(3), UpFrom(3), To(3), From(3), Full,
+    ];
+    for a in intervals.iter() {
+        for p in -1..=4 {
+            assert_eq!(a.contains_by(&p, Ord::cmp), a.contains(&p));
+        }
+    }
+
+    // Reversing the order swaps the sense of the bounds.
+    assert!(UpTo(3).contains_by(&4, |l: &i32, r: &i32| r.cmp(l)));
+    assert!(!UpTo(3).contains_by(&2, |l: &i32, r: &i32| r.cmp(l)));
+    assert!(Closed(3, 0).contains_by(&2, |l: &i32, r: &i32| r.cmp(l)));
+}
+
+#[test]
+fn intersects_by() {
+    let intervals: [RawInterval<i32>; 14] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), Closed(3, 5), Open(3, 5), Closed(-4, -1), UpTo(3),
+        UpFrom(3), To(3), From(3), Full,
+    ];
+    for a in intervals.iter() {
+        for b in intervals.iter() {
+            assert_eq!(a.intersects_by(b, Ord::cmp), a.intersects(b),
+                "{:?} intersects {:?}", a, b);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////
+// Topological operation tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn closure() {
+    assert_eq!(RawInterval::<i32>::Empty.closure(), Empty);
+    assert_eq!(Point(3).closure(),                  Point(3));
+    assert_eq!(Open(0, 3).closure(),                Closed(0, 3));
+    assert_eq!(LeftOpen(0, 3).closure(),            Closed(0, 3));
+    assert_eq!(RightOpen(0, 3).closure(),           Closed(0, 3));
+    assert_eq!(Closed(0, 3).closure(),              Closed(0, 3));
+    assert_eq!(UpTo(3).closure(),                   To(3));
+    assert_eq!(UpFrom(3).closure(),                 From(3));
+    assert_eq!(To(3).closure(),                     To(3));
+    assert_eq!(From(3).closure(),                   From(3));
+    assert_eq!(RawInterval::<i32>::Full.closure(),  Full);
+}
+
+#[test]
+fn interior() {
+    assert_eq!(RawInterval::<i32>::Empty.interior(), Empty);
+    assert_eq!(Point(3).interior(),                  Empty);
+    assert_eq!(Open(0, 3).interior(),                Open(0, 3));
+    assert_eq!(LeftOpen(0, 3).interior(),            Open(0, 3));
+    assert_eq!(RightOpen(0, 3).interior(),           Open(0, 3));
+    assert_eq!(Closed(0, 3).interior(),              Open(0, 3));
+    assert_eq!(Closed(3, 3).interior(),              Empty);
+    assert_eq!(UpTo(3).interior(),                   UpTo(3));
+    assert_eq!(UpFrom(3).interior(),                 UpFrom(3));
+    assert_eq!(To(3).interior(),                     UpTo(3));
+    assert_eq!(From(3).interior(),                   UpFrom(3));
+    assert_eq!(RawInterval::<i32>::Full.interior(),  Full);
+}
+
+////////////////////////////////////////////////////////////////////////////
+// Display tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn display() {
+    let intervals: [RawInterval<i32>; 11] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), UpTo(3), UpFrom(3), To(3), From(3), Full,
+    ];
+    let expected = [
+        "{}", "3", "(0, 3)", "(0, 3]", "[0, 3)",
+        "[0, 3]", "(-inf, 3)", "(3, inf)", "(-inf, 3]", "[3, inf)",
+        "(-inf, inf)",
+    ];
+    for (a, e) in intervals.iter().zip(expected.iter()) {
+        assert_eq!(format!("{}", a), *e);
+    }
+}
+
+#[test]
+fn display_alternate() {
+    let intervals: [RawInterval<i32>; 11] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), UpTo(3), UpFrom(3), To(3), From(3), Full,
+    ];
+    let expected = [
+        "∅", "3", "(0, 3)", "(0, 3]", "[0, 3)",
+        "[0, 3]", "(-∞, 3)", "(3, ∞)", "(-∞, 3]", "[3, ∞)",
+        "(-∞, ∞)",
+    ];
+    for (a, e) in intervals.iter().zip(expected.iter()) {
+        assert_eq!(format!("{:#}", a), *e);
+    }
+}
+
+#[test]
+fn to_latex() {
+    let intervals: [RawInterval<i32>; 11] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), UpTo(3), UpFrom(3), To(3), From(3), Full,
+    ];
+    let expected = [
+        "\\emptyset", "\\{3\\}", "(0, 3)", "(0, 3]", "[0, 3)",
+        "[0, 3]", "(-\\infty, 3)", "(3, \\infty)", "(-\\infty, 3]",
+        "[3, \\infty)", "(-\\infty, \\infty)",
+    ];
+    for (a, e) in intervals.iter().zip(expected.iter()) {
+        assert_eq!(a.to_latex(), *e);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////
 // Set law tests
 ////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,464 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Selection`].
+//!
+//! [`Selection`] struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::float::TotalF64;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////
+// Set operation tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn minus_adjacent_members() {
+    let sel: Selection<i32> = vec![
+        Interval::closed(18, 19),
+        Interval::closed(21, 24),
+    ].into_iter().collect();
+
+    let sub: Selection<i32> = Selection::from(Interval::closed(17, 19));
+    assert_eq!(sel.minus(&sub).interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(21, 24)]);
+
+    let sub: Selection<i32> = Selection::from(Interval::closed(21, 25));
+    assert_eq!(sel.minus(&sub).interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(18, 19)]);
+}
+
+#[test]
+fn eq_ignores_representation() {
+    let a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    let mut b = a.clone();
+    b.minus_in_place(Interval::point(5));
+    b.union_in_place(Interval::point(5));
+    assert_eq!(a, b);
+
+    let patch = Selection::diff(&a, &b);
+    assert!(patch.is_empty());
+}
+
+#[test]
+fn same_set_across_bound_forms() {
+    let closed: Selection<i32> = vec![
+        Interval::closed(1, 4),
+        Interval::closed(10, 10),
+    ].into_iter().collect();
+
+    let mut built = Selection::new();
+    built.union_in_place(Interval::left_open(0, 2));
+    built.union_in_place(Interval::open(2, 5));
+    built.union_in_place(Interval::point(2));
+    built.union_in_place(Interval::open(9, 11));
+    assert!(built.same_set(&closed));
+
+    let mut carved = Selection::from(Interval::right_open(1, 11));
+    carved.minus_in_place(Interval::open(4, 10));
+    assert!(carved.same_set(&closed));
+}
+
+#[test]
+fn intersect_without_common_points() {
+    let a: Selection<i32> = Selection::from(Interval::closed(5, 8));
+    let b: Selection<i32> = Selection::from(Interval::closed(1, 4));
+    assert!(a.intersect(&b).is_empty());
+
+    let b: Selection<i32> = Selection::from(Interval::closed(1, 5));
+    assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(), [
+        Interval::point(5)]);
+}
+
+#[test]
+fn covers_after_minus() {
+    let mut sel: Selection<i32> = Selection::new();
+    sel.union_in_place(Interval::point(2));
+    sel.minus_in_place(Interval::point(1));
+    assert_eq!(sel.covers(&Interval::point(2)), Ok(()));
+
+    let gaps = sel.covers(&Interval::closed(1, 3)).unwrap_err();
+    assert_eq!(gaps.interval_iter().collect::<Vec<_>>(), [
+        Interval::point(1),
+        Interval::point(3),
+    ]);
+}
+
+#[test]
+fn complement_within_after_minus() {
+    let mut sel: Selection<i32> = Selection::new();
+    sel.union_in_place(Interval::point(2));
+    sel.minus_in_place(Interval::point(1));
+    assert!(sel.complement_within(&Interval::point(2)).is_empty());
+    assert_eq!(sel.complement_within(&Interval::point(2)), Selection::new());
+}
+
+#[test]
+fn intersect_in_place_enclosing_interval() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(3, 5));
+    sel.intersect_in_place(Interval::closed(0, 10));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(3, 5)]);
+
+    let mut sel: Selection<i32> = vec![
+        Interval::closed(0, 4),
+        Interval::closed(8, 9),
+        Interval::closed(12, 20),
+    ].into_iter().collect();
+    sel.intersect_in_place(Interval::closed(6, 10));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(8, 9)]);
+}
+
+#[test]
+fn is_disjoint_adjacent_members() {
+    let a: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    let b: Selection<i32> = Selection::from(Interval::closed(5, 9));
+    assert!(a.is_disjoint(&b));
+    assert!(b.is_disjoint(&a));
+    assert!(!a.intersects(&b));
+
+    let a: Selection<i32> = Selection::from(Interval::point(4));
+    let b: Selection<i32> = Selection::from(Interval::point(3));
+    assert!(a.is_disjoint(&b));
+    assert!(!a.intersects(&b));
+
+    let a: Selection<i32> = vec![
+        Interval::closed(0, 4),
+        Interval::closed(10, 14),
+    ].into_iter().collect();
+    let b: Selection<i32> = Selection::from(Interval::closed(5, 9));
+    assert!(a.is_disjoint(&b));
+
+    let b: Selection<i32> = Selection::from(Interval::closed(5, 10));
+    assert!(!a.is_disjoint(&b));
+    assert!(a.intersects(&b));
+}
+
+#[test]
+fn intersection_iter_after_minus() {
+    let mut sel: Selection<i32> = Selection::new();
+    sel.union_in_place(Interval::point(2));
+    sel.minus_in_place(Interval::point(1));
+    let rest = Selection::from(Interval::point(2)).minus(&sel);
+
+    let other: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    assert_eq!(rest.intersection_iter(&other).count(), 0);
+    assert_eq!(other.intersection_iter(&rest).count(), 0);
+}
+
+#[test]
+fn intersects_by_several_members() {
+    let by_decade = |a: &i32, b: &i32| (a / 10).cmp(&(b / 10));
+
+    let a: Selection<i32> = vec![
+        Interval::closed(0, 3),
+        Interval::closed(41, 45),
+    ].into_iter().collect();
+    let b: Selection<i32> = vec![
+        Interval::closed(12, 14),
+        Interval::closed(48, 60),
+    ].into_iter().collect();
+    assert!(a.intersects_by(&b, by_decade));
+    assert!(b.intersects_by(&a, by_decade));
+
+    let b: Selection<i32> = vec![
+        Interval::closed(12, 14),
+        Interval::closed(25, 38),
+        Interval::closed(50, 60),
+    ].into_iter().collect();
+    assert!(!a.intersects_by(&b, by_decade));
+    assert!(!b.intersects_by(&a, by_decade));
+}
+
+#[test]
+fn coalesce_within_unbounded_members() {
+    let sel: Selection<i32> = vec![
+        Interval::unbounded_to(0),
+        Interval::closed(3, 5),
+        Interval::closed(9, 10),
+        Interval::unbounded_from(12),
+    ].into_iter().collect();
+
+    assert_eq!(sel.coalesce_within(2).interval_iter().collect::<Vec<_>>(), [
+        Interval::unbounded_to(0),
+        Interval::closed(3, 5),
+        Interval::closed(9, 10),
+        Interval::unbounded_from(12)]);
+    assert_eq!(sel.coalesce_within(4).interval_iter().collect::<Vec<_>>(), [
+        Interval::unbounded_to(5),
+        Interval::unbounded_from(9)]);
+    assert_eq!(sel.coalesce_within(5).interval_iter().collect::<Vec<_>>(), [
+        Interval::full()]);
+}
+
+#[test]
+fn simplify_merging_chooses_nearest_neighbor() {
+    let sel: Selection<i32> = vec![
+        Interval::closed(-5, 0),
+        Interval::closed(3, 3),
+        Interval::closed(5, 10),
+        Interval::closed(14, 15),
+        Interval::closed(18, 18),
+    ].into_iter().collect();
+
+    assert_eq!(sel.simplify_merging(2).interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(-5, 0),
+        Interval::closed(3, 10),
+        Interval::closed(14, 18)]);
+
+    let sel: Selection<i32> = Selection::from(Interval::closed(3, 4));
+    assert!(sel.simplify_merging(2).is_empty());
+}
+
+#[test]
+fn split_at_points_open_bounds() {
+    let a = TotalF64(1.0);
+    let b = TotalF64(2.0);
+    let c = TotalF64(3.0);
+    let sel: Selection<TotalF64> = vec![
+        Interval::right_open(a, b),
+        Interval::unbounded_from(c),
+    ].into_iter().collect();
+
+    assert_eq!(sel.split_at_points(&[c, a, b]), [
+        Interval::right_open(a, b),
+        Interval::unbounded_from(c)]);
+
+    let mid = TotalF64(1.5);
+    assert_eq!(sel.split_at_points(&[mid, TotalF64(4.0)]), [
+        Interval::right_open(a, mid),
+        Interval::right_open(mid, b),
+        Interval::right_open(c, TotalF64(4.0)),
+        Interval::unbounded_from(TotalF64(4.0))]);
+}
+
+#[test]
+fn shift_matches_interval_shift() {
+    let interval: Interval<i32> = Interval::closed(0, i32::MAX);
+    assert_eq!(Selection::from(interval).shift_down(1),
+        Selection::from(interval.shift_down(1)));
+    assert_eq!(Selection::from(interval).shift_down(1),
+        Selection::from(Interval::closed(-1, i32::MAX - 1)));
+
+    let interval: Interval<i32> = Interval::closed(i32::MIN, 0);
+    assert_eq!(Selection::from(interval).shift(1),
+        Selection::from(Interval::closed(i32::MIN + 1, 1)));
+
+    let sel: Selection<i32> = Selection::from(Interval::closed(0, 5))
+        .complement()
+        .intersect(&Selection::from(Interval::closed(-10, 10)));
+    let expected: Selection<i32> = vec![
+        Interval::closed(-7, 2),
+        Interval::closed(9, 13),
+    ].into_iter().collect();
+    assert_eq!(sel.shift(3), expected);
+    assert_eq!(expected.shift_down(3), sel);
+}
+
+#[test]
+fn jaccard_extreme_bounds() {
+    let full: Selection<i128> = Selection::from(Interval::full());
+    assert_eq!(full.jaccard(&full), 1.0);
+    assert_eq!(full.jaccard(&Selection::from(Interval::closed(0, i128::MAX))),
+        0.5);
+
+    let a: Selection<u128> = vec![
+        Interval::closed(u128::MAX - 9, u128::MAX - 5),
+        Interval::closed(u128::MAX - 2, u128::MAX),
+    ].into_iter().collect();
+    let b: Selection<u128> = Selection::from(
+        Interval::closed(u128::MAX - 9, u128::MAX));
+    assert_eq!(a.jaccard(&b), 0.8);
+    assert_eq!(Selection::<u128>::from(Interval::full()).jaccard(&b),
+        10.0 / 2f64.powi(128));
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn shift_overflow() {
+    let sel: Selection<u8> = Selection::from(Interval::closed(0, 250));
+    let _ = sel.shift(10u8);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "mapping function is not increasing")]
+fn map_monotonic_decreasing() {
+    let sel: Selection<i32> = vec![
+        Interval::closed(0, 5),
+        Interval::closed(10, 15),
+    ].into_iter().collect();
+
+    let _ = sel.map_monotonic(|p| -p);
+}
+
+#[test]
+fn reduce_to_zero_intervals() {
+    let sel: Selection<i32> = vec![
+        Interval::closed(0, 4),
+        Interval::closed(10, 12),
+        Interval::closed(15, 30),
+    ].into_iter().collect();
+
+    let (reduced, filled) = sel.reduce_to(0);
+    assert_eq!(reduced, Selection::from(Interval::closed(0, 30)));
+    assert_eq!(filled, 9);
+
+    let (reduced, filled) = Selection::<i32>::new().reduce_to(0);
+    assert!(reduced.is_empty());
+    assert_eq!(filled, 0);
+}
+
+#[test]
+fn mask_round_trip() {
+    let universe: Interval<usize> = Interval::closed(10, 29);
+    let sel: Selection<usize> = vec![
+        Interval::closed(8, 10),
+        Interval::closed(15, 15),
+        Interval::closed(20, 23),
+        Interval::closed(29, 40),
+    ].into_iter().collect();
+
+    let mask = sel.to_mask(&universe);
+    assert_eq!(mask.len(), 20);
+    assert_eq!(Selection::from_mask(&mask, &universe),
+        sel.restricted_to(&universe));
+
+    assert!(sel.to_mask(&Interval::empty()).is_empty());
+    assert!(Selection::from_mask(&[], &Interval::empty()).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "mask length does not match universe")]
+fn from_mask_wrong_length() {
+    let _ = Selection::from_mask(&[true, false], &Interval::closed(0, 2));
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Iterator tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn points_double_ended() {
+    let sel: Selection<i32> = vec![
+        Interval::closed(0, 2),
+        Interval::closed(10, 12),
+    ].into_iter().collect();
+
+    let mut points = sel.points();
+    assert_eq!(points.next(), Some(0));
+    assert_eq!(points.next_back(), Some(12));
+    assert_eq!(points.next(), Some(1));
+    assert_eq!(points.collect::<Vec<_>>(), [2, 10, 11]);
+
+    let mut points = sel.clone().into_iter();
+    assert_eq!(points.next_back(), Some(12));
+    assert_eq!(points.next(), Some(0));
+    assert_eq!(points.rev().collect::<Vec<_>>(), [11, 10, 2, 1]);
+
+    let sel: Selection<i32> = Selection::from(Interval::closed(0, 3));
+    let mut points = sel.points();
+    assert_eq!(points.next(), Some(0));
+    assert_eq!(points.next_back(), Some(3));
+    assert_eq!(points.collect::<Vec<_>>(), [1, 2]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Assigning operator tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn bitor_assign() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 3));
+    sel |= Interval::closed(4, 6);
+    sel |= Interval::closed(10, 12);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 6),
+        Interval::closed(10, 12)]);
+
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 3));
+    sel |= Selection::from(Interval::closed(2, 8));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 8)]);
+}
+
+#[test]
+fn bitand_assign() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    sel &= Interval::closed(4, 16);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(4, 10)]);
+
+    let mut other: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    other |= Interval::closed(8, 20);
+    sel &= other;
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(4, 5),
+        Interval::closed(8, 10)]);
+}
+
+#[test]
+fn bitand_assign_without_common_points() {
+    let mut sel: Selection<i32> = Selection::from(Interval::point(4));
+    sel &= Selection::from(Interval::point(3));
+    assert!(sel.is_empty());
+    assert_eq!(sel, Selection::new());
+}
+
+#[test]
+fn sub_assign() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    sel -= Interval::closed(4, 6);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 3),
+        Interval::closed(7, 10)]);
+
+    sel -= Selection::from(Interval::closed(2, 8));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 1),
+        Interval::closed(9, 10)]);
+}
+
+#[test]
+fn bitxor_assign() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    sel ^= Interval::closed(5, 15);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 4),
+        Interval::closed(11, 15)]);
+
+    sel ^= Selection::from(Interval::closed(0, 15));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(5, 10)]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////
+// Display tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn display() {
+    let mut sel: Selection<i32> = Selection::new();
+    assert_eq!(format!("{}", sel), "{}");
+    assert_eq!(format!("{:#}", sel), "∅");
+
+    sel.union_in_place(Interval::closed(0, 3));
+    sel.union_in_place(Interval::closed(7, 9));
+    assert_eq!(format!("{}", sel), "[0, 3] U [7, 9]");
+    assert_eq!(format!("{:#}", sel), "[0, 3] ∪ [7, 9]");
+}
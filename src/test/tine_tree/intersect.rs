@@ -34,7 +34,7 @@ fn disjoint_aggregation() {
 	t.intersect_in_place(&UpFrom(50));
 	t.intersect_in_place(&Empty);
 
-    assert_eq!(t.iter_intervals().collect::<Vec<_>>(), []);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), []);
 }
 
 #[test]
@@ -51,7 +51,22 @@ fn nested_aggregation() {
     t.intersect_in_place(&UpFrom(20));
     t.intersect_in_place(&Point(25));
 
-    assert_eq!(t.iter_intervals().collect::<Vec<_>>(), [Point(25)]);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), [Point(25)]);
+}
+
+#[test]
+fn enclosing_aggregation() {
+    let mut t: TineTree<i32> = Closed(3, 5).into();
+    t.intersect_in_place(&Closed(0, 10));
+
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), [Closed(3, 5)]);
+
+    let mut t: TineTree<i32> = Closed(0, 4).into();
+    t.union_in_place(&Closed(8, 9));
+    t.union_in_place(&Closed(12, 20));
+    t.intersect_in_place(&Closed(6, 10));
+
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), [Closed(8, 9)]);
 }
 
 
@@ -553,3 +568,19 @@ fn full() {
     assert_eq_i!(a.intersect(&TineTree::from(From(0))),           [From(0)]);
     assert_eq_i!(a.intersect(&TineTree::from(Full)),              [Full]);
 }
+
+#[test]
+fn spanning() {
+    let a: TineTree<i32> = vec![
+        Closed(0, 3),
+        Closed(5, 8),
+        Closed(10, 13),
+    ].into_iter().into();
+
+    assert_eq_i!(a.intersect(&TineTree::from(Closed(2, 11))),
+        [Closed(2, 3), Closed(5, 8), Closed(10, 11)]);
+    assert_eq_i!(TineTree::from(Closed(2, 11)).intersect(&a),
+        [Closed(2, 3), Closed(5, 8), Closed(10, 11)]);
+    assert_eq_i!(a.intersect(&TineTree::from(Full)),
+        [Closed(0, 3), Closed(5, 8), Closed(10, 13)]);
+}
@@ -515,3 +515,14 @@ fn full() {
     assert_eq_i!(a.minus(&TineTree::from(From(0))),           [UpTo(0)]);
     assert_eq_i!(a.minus(&TineTree::from(Full)),              []);
 }
+
+#[test]
+fn excluded_point_split() {
+    let mut a: TineTree<i32> = Open(0, 3).into();
+    a.union_in_place(&Open(3, 6));
+
+    assert_eq_i!(a.minus(&TineTree::from(Open(-1, 3))),       [Open(3, 6)]);
+    assert_eq_i!(a.minus(&TineTree::from(LeftOpen(-1, 3))),   [Open(3, 6)]);
+    assert_eq_i!(a.minus(&TineTree::from(Open(3, 7))),        [Open(0, 3)]);
+    assert_eq_i!(a.minus(&TineTree::from(RightOpen(3, 7))),   [Open(0, 3)]);
+}
@@ -0,0 +1,73 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+
+
+// Local imports.
+use crate::raw_interval::RawInterval;
+use crate::tine_tree::TineTree;
+
+// Local enum shortcuts.
+use crate::raw_interval::RawInterval::*;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Symmetric difference tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn empty() {
+    let a: TineTree<i32> = Empty.into();
+
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Empty)),         []);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Point(3))),      [Point(3)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Closed(0, 3))),  [Closed(0, 3)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Full)),          [Full]);
+}
+
+#[test]
+fn full() {
+    let a: TineTree<i32> = Full.into();
+
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Empty)),         [Full]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Point(0))),      [UpTo(0), UpFrom(0)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Open(0, 3))),    [To(0), From(3)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Closed(0, 3))),  [UpTo(0), UpFrom(3)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(UpTo(0))),       [From(0)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(From(0))),       [UpTo(0)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Full)),          []);
+}
+
+#[test]
+fn shared_bounds() {
+    let a: TineTree<i32> = Closed(0, 3).into();
+
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Open(0, 3))),    [Point(0), Point(3)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(RightOpen(3, 6))), [RightOpen(0, 3), Open(3, 6)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(UpFrom(3))),     [From(0)]);
+    assert_eq_i!(a.symmetric_difference(&TineTree::from(Point(3))),      [RightOpen(0, 3)]);
+}
+
+#[test]
+fn matches_union_minus_intersect() {
+    let intervals: [RawInterval<i32>; 12] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), Closed(3, 5), UpTo(3), UpFrom(3), To(3), From(3), Full,
+    ];
+    for a in intervals.iter() {
+        for b in intervals.iter() {
+            let mut a: TineTree<i32> = (*a).into();
+            a.union_in_place(&Open(6, 9));
+            let b: TineTree<i32> = (*b).into();
+
+            let expected = a.union(&b).minus(&a.intersect(&b));
+            assert_eq!(a.symmetric_difference(&b), expected,
+                "{:?} ^ {:?}", a, b);
+        }
+    }
+}
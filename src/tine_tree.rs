@@ -14,11 +14,13 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::bound::UpperBound;
 use crate::raw_interval::RawInterval;
 use crate::tine::Tine;
 use crate::utility::Few;
 
 // Standard library imports.
+use std::borrow::Borrow;
 use std::collections::BTreeSet;
 use std::collections::btree_set;
 use std::collections;
@@ -94,7 +96,11 @@ impl<T> TineTree<T> where T: Ord + Clone {
     }
 
     /// Returns `true` if the `TineTree` contains the given point.
-    pub(in crate) fn contains(&self, point: &T) -> bool {
+    pub(in crate) fn contains<Q>(&self, point: &Q) -> bool
+        where
+            T: Borrow<Q>,
+            Q: Ord + ?Sized,
+    {
         // TODO(Sky): Could be optimized by splitting the tree and looking around.
         for interval in self.interval_iter() {
             if interval.contains(point) {return true;}
@@ -165,24 +171,29 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// `TineTree`s.
     pub(in crate) fn intersect(&self, other: &Self) -> Self {
         let mut intersection = Self::new();
-        let mut self_intervals = self.interval_iter();
-        let mut other_intervals = other.interval_iter();
-
-        while let Some(self_interval) = self_intervals.next() {
-            'segment: loop {
-                if let Some(other_interval) = other_intervals.next() {
-                    let i = self_interval.intersect(&other_interval);
-                    if !i.is_empty() {
-                        intersection.union_in_place(&i);
-                    } else {
-                        // Nothing else overlaps in this segment.
-                        break 'segment;
-                    }
+        let mut self_intervals = self.interval_iter().peekable();
+        let mut other_intervals = other.interval_iter().peekable();
 
-                } else {
-                    // Nothing else overlaps anywhere.
-                    return intersection;
-                }
+        while let (Some(self_interval), Some(other_interval))
+            = (self_intervals.peek(), other_intervals.peek())
+        {
+            let i = self_interval.intersect(other_interval);
+            if !i.is_empty() {
+                intersection.union_in_place(&i);
+            }
+
+            // Advance whichever interval ends first, as it cannot overlap
+            // anything further in the other tree.
+            let self_upper = UpperBound(self_interval
+                .upper_bound()
+                .expect("nonempty interval"));
+            let other_upper = UpperBound(other_interval
+                .upper_bound()
+                .expect("nonempty interval"));
+            if self_upper <= other_upper {
+                self_intervals.next();
+            } else {
+                other_intervals.next();
             }
         }
         intersection
@@ -208,6 +219,57 @@ impl<T> TineTree<T> where T: Ord + Clone {
         minus
     }
 
+    /// Returns a `TineTree` containing all points present in exactly one of
+    /// the `TineTree`s.
+    pub(in crate) fn symmetric_difference(&self, other: &Self) -> Self {
+        use Bound::*;
+        use Tine::*;
+        use std::cmp::Ordering::*;
+
+        let mut self_tines = self.0.iter().peekable();
+        let mut other_tines = other.0.iter().peekable();
+        let mut tines = Vec::with_capacity(self.0.len() + other.0.len());
+
+        // Sweep the tines of both trees in order, tracking whether the points
+        // just before the current tine are in each tree.
+        let (mut in_self, mut in_other) = (false, false);
+        loop {
+            let ord = match (self_tines.peek(), other_tines.peek()) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None)    => Less,
+                (None,    Some(_)) => Greater,
+                (None,    None)    => break,
+            };
+            let a = if ord != Greater { self_tines.next() } else { None };
+            let b = if ord != Less { other_tines.next() } else { None };
+
+            let (at_self, after_self) = tine_membership(a, in_self);
+            let (at_other, after_other) = tine_membership(b, in_other);
+            let before = in_self != in_other;
+            let at = at_self != at_other;
+            let after = after_self != after_other;
+            in_self = after_self;
+            in_other = after_other;
+
+            let point = a.or(b).and_then(Tine::as_ref).cloned();
+            let tine = match (point, before, at, after) {
+                (None,    _,     _,     true)  => Some(Lower(Infinite)),
+                (None,    true,  _,     false) => Some(Upper(Infinite)),
+                (Some(p), false, true,  true)  => Some(Lower(Include(p))),
+                (Some(p), false, false, true)  => Some(Lower(Exclude(p))),
+                (Some(p), true,  true,  false) => Some(Upper(Include(p))),
+                (Some(p), true,  false, false) => Some(Upper(Exclude(p))),
+                (Some(p), false, true,  false) => Some(Point(Include(p))),
+                (Some(p), true,  false, true)  => Some(Point(Exclude(p))),
+                _                              => None,
+            };
+            if let Some(tine) = tine {
+                tines.push(tine);
+            }
+        }
+        TineTree(BTreeSet::from_iter(tines))
+    }
+
     /// Returns the smallest `RawInterval` containing all of the points in the 
     /// `TineTree`.
     pub(in crate) fn enclose(&self) -> RawInterval<T> {
@@ -301,98 +363,54 @@ impl<T> TineTree<T> where T: Ord + Clone {
     }
 
     fn intersect_proper_interval(&mut self, l: Tine<T>, u: Tine<T>) {
-        let mut ts = self.interior_split_for_proper_interval(&l, &u);
+        let ts = self.interior_split_for_proper_interval(&l, &u);
+
+        // We need to detect whether the points just inside each end of the
+        // interval are inside an interval of the tree. To do this, we look at
+        // the nearest tine at or beyond each end, in order of preference: a
+        // tine on the end, the nearest inner tine, and the nearest outer tine.
+        let inside_l = match (&ts[2], &ts[1], &ts[0]) {
+            (Some(t), _,       _      ) => t.is_lower_bound(),
+            (None,    Some(t), _      ) => t.is_upper_bound(),
+            (None,    None,    Some(t)) => t.is_lower_bound(),
+            (None,    None,    None   ) => false,
+        };
+        let inside_u = match (&ts[3], &ts[4], &ts[5]) {
+            (Some(t), _,       _      ) => t.is_upper_bound(),
+            (None,    Some(t), _      ) => t.is_lower_bound(),
+            (None,    None,    Some(t)) => t.is_upper_bound(),
+            (None,    None,    None   ) => false,
+        };
 
         // Merge tines if overlap or use given ones. We should only have `None`
         // in the case of a intersection annhiliation.
-        let merged_l = if ts[2].is_some() {
-            ts[2].take().and_then(|lower| lower.intersect(&l))
-        } else {
-            Some(l)
+        let [_, _, tl, tu, _, _] = ts;
+        let merged_l = match tl {
+            Some(lower) => lower.intersect(&l),
+            None        => Some(l),
         };
-
-        let merged_u = if ts[3].is_some() {
-            ts[3].take().and_then(|upper| upper.intersect(&u))
-        } else {
-            Some(u)
+        let merged_u = match tu {
+            Some(upper) => upper.intersect(&u),
+            None        => Some(u),
         };
-        
-        // Ensure inner tines have the correct bounds.
-        debug_assert!(merged_l
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(true));
-        debug_assert!(merged_u
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(true));
 
-        
-        // We need to detect whether the point is inside or outside an interval.
-        // To do this, we look at the tines inside and outside the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(false);
-        let closed_after = ts[5]
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(false);
-
-        let in_l = ts[1]
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(false);
-        let in_r = ts[4]
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(false);
-
-
-        // Insert tines into the tree, ignoring them if the are not wrapped by a
-        // surrounding interval, or not wrapping a surrounding interval.
-        match (open_before, merged_l, in_l, in_r, merged_u, closed_after) {
-            (_,     Some(l), true,  true,  Some(u), _   )  |
-            (_,     Some(l), false, false, Some(u), _   )  => {
-                // (   ) (   )
-                //   (     )
-                //     O R
-                // (     )
-                //   ( )
-                //     O R
-                // (     )
-                // (  )
-                //     O R
-                // (     )
-                //    (  )
+        // Insert tines into the tree, ignoring them if they are not inside a
+        // surrounding interval. Merged tines which became points are always
+        // inserted, as the point is shared by both intervals.
+        //   (     )      (     )      ( ) ( )      ( )
+        //     ( )      (   )        (       )            ( )
+        //     O R          O R          O R          O R
+        if let Some(l) = merged_l {
+            if inside_l || l.is_point_include() {
+                debug_assert!(l.is_lower_bound() || l.is_point_include());
                 self.0.insert(l);
+            }
+        }
+        if let Some(u) = merged_u {
+            if inside_u || u.is_point_include() {
+                debug_assert!(u.is_upper_bound() || u.is_point_include());
                 self.0.insert(u);
-            },
-            (true, Some(l),  true,  false, _,       false) => {
-                // (   )
-                //   (   )
-                //     O R
-                // (   ) ( )
-                //   (   )
-                self.0.insert(l);
-            },
-            (false, _,       false, true,  Some(u), true)  => {
-                //   (   )
-                // (   )
-                //     O R
-                // ( ) (   )
-                //   (   )
-                self.0.insert(u);
-            },
-            (false, _,       false, false, _,       false) => {
-                // )     (
-                // (     )
-                //     O R
-                //   ( )
-                // (     )
-                /* Do nothing. */
-            },
-            _ => unreachable!("invalid bounds for intersection interval"),
+            }
         }
     }
 
@@ -701,7 +719,6 @@ impl<T> TineTree<T> where T: Ord + Clone {
             .map(Tine::is_upper_bound)
             .unwrap_or(false);
         
-        println!("Minus {} {} {} {}", open_before, merged_l.is_some(), merged_u.is_some(), closed_after);
         // Insert tines into the tree, ignoring them if the are not wrapped by a
         // surounding interval.
         use Bound::*;
@@ -918,6 +935,22 @@ impl<T> TineTree<T> where T: Ord + Clone {
     // Iterator conversions
     ////////////////////////////////////////////////////////////////////////////
 
+    /// Returns `true` if the `Tine`s of the tree alternate between lower and
+    /// upper bounds, so that every interval in the tree is closed off.
+    pub(in crate) fn is_balanced(&self) -> bool {
+        let mut inside = false;
+        for tine in self.0.iter() {
+            inside = match (tine, inside) {
+                (Tine::Lower(_), false)                 => true,
+                (Tine::Upper(_), true)                  => false,
+                (Tine::Point(Bound::Include(_)), false) => false,
+                (Tine::Point(Bound::Exclude(_)), true)  => true,
+                _                                       => return false,
+            };
+        }
+        !inside
+    }
+
     /// Returns an iterator over each of the `RawInterval`s in the tree.
     pub(in crate) fn interval_iter(&self) -> Iter<'_, T> {
         Iter {
@@ -928,6 +961,26 @@ impl<T> TineTree<T> where T: Ord + Clone {
     }
 }
 
+/// Returns whether the point at the given `Tine` and the points just after it
+/// are in the tree, given whether the points just before it are. A missing
+/// `Tine` leaves the membership unchanged.
+fn tine_membership<T>(tine: Option<&Tine<T>>, before: bool) -> (bool, bool) {
+    use Bound::*;
+    use Tine::*;
+    match tine {
+        None                     => (before, before),
+        Some(&Lower(Infinite))   => (true,   true),
+        Some(&Lower(Include(_))) => (true,   true),
+        Some(&Lower(Exclude(_))) => (false,  true),
+        Some(&Point(Include(_))) => (true,   false),
+        Some(&Point(Exclude(_))) => (false,  true),
+        Some(&Upper(Include(_))) => (true,   false),
+        Some(&Upper(Exclude(_))) => (false,  false),
+        Some(&Upper(Infinite))   => (true,   false),
+        Some(&Point(Infinite))   => unreachable!("invalid Tine value"),
+    }
+}
+
 impl<T> Default for TineTree<T> where T: Ord + Clone {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,544 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides intervals over circular domains, which may wrap past the end of
+//! the domain back to its start.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::IntervalPoint;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// WrappingInterval
+////////////////////////////////////////////////////////////////////////////////
+/// An interval over a circular domain, such as angles or times of day.
+///
+/// A `WrappingInterval` includes its start and end points. If its start point
+/// is greater than its end point, it wraps past the end of the domain and
+/// continues from the start of the domain.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::wrapping::WrappingInterval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// // Minutes of the day, from 22:00 to 01:59.
+/// let day: Interval<u32> = Interval::right_open(0, 24 * 60);
+/// let night = WrappingInterval::new(day, 22 * 60, 2 * 60 - 1);
+///
+/// assert!(night.is_wrapping());
+/// assert!(night.contains(&(23 * 60)));
+/// assert!(night.contains(&30));
+/// assert!(!night.contains(&(12 * 60)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WrappingInterval<T> {
+    /// The circular domain of the interval.
+    domain: Interval<T>,
+    /// The included start point.
+    start: T,
+    /// The included end point.
+    end: T,
+}
+
+impl<T> WrappingInterval<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `WrappingInterval` over the given domain from the
+    /// given start point to the given end point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain does not contain both points.
+    pub fn new(domain: Interval<T>, start: T, end: T) -> Self {
+        assert!(domain.contains(&start) && domain.contains(&end),
+            "wrapping interval points must be within the domain");
+        WrappingInterval { domain, start, end }
+    }
+
+    /// Returns the circular domain of the `WrappingInterval`.
+    #[inline]
+    pub fn domain(&self) -> &Interval<T> {
+        &self.domain
+    }
+
+    /// Returns the start point of the `WrappingInterval`.
+    #[inline]
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// Returns the end point of the `WrappingInterval`.
+    #[inline]
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    /// Returns `true` if the `WrappingInterval` wraps past the end of its
+    /// domain.
+    #[inline]
+    pub fn is_wrapping(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Returns `true` if the `WrappingInterval` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        if self.is_wrapping() {
+            self.domain.contains(point)
+                && (*point >= self.start || *point <= self.end)
+        } else {
+            self.start <= *point && *point <= self.end
+        }
+    }
+
+    /// Returns the `Selection` of the points of the domain contained in the
+    /// `WrappingInterval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::wrapping::WrappingInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees: Interval<u32> = Interval::right_open(0, 360);
+    /// let arc = WrappingInterval::new(degrees, 350, 10);
+    ///
+    /// assert_eq!(arc.to_selection().interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(350, 359),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_selection(&self) -> Selection<T> {
+        if !self.is_wrapping() {
+            return Selection::from(
+                Interval::closed(self.start.clone(), self.end.clone()));
+        }
+        let mut selection = Selection::from(self.domain.clone());
+        selection.minus_in_place(
+            Interval::open(self.end.clone(), self.start.clone()));
+        selection
+    }
+
+    /// Returns `true` if the `WrappingInterval`s share any points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::wrapping::WrappingInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees: Interval<u32> = Interval::right_open(0, 360);
+    /// let a = WrappingInterval::new(degrees, 350, 10);
+    ///
+    /// assert!(a.intersects(&WrappingInterval::new(degrees, 5, 20)));
+    /// assert!(!a.intersects(&WrappingInterval::new(degrees, 20, 340)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.to_selection().is_disjoint(&other.to_selection())
+    }
+
+    /// Returns the `Selection` of the points of the domain contained in both
+    /// `WrappingInterval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::wrapping::WrappingInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees: Interval<u32> = Interval::right_open(0, 360);
+    /// let a = WrappingInterval::new(degrees, 300, 60);
+    /// let b = WrappingInterval::new(degrees, 30, 330);
+    ///
+    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(30, 60),
+    ///     Interval::closed(300, 330),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Selection<T> {
+        self.to_selection().intersect(&other.to_selection())
+    }
+
+    /// Returns the `Selection` of the points of the domain contained in
+    /// either `WrappingInterval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::wrapping::WrappingInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees: Interval<u32> = Interval::right_open(0, 360);
+    /// let a = WrappingInterval::new(degrees, 350, 10);
+    /// let b = WrappingInterval::new(degrees, 5, 20);
+    ///
+    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 20),
+    ///     Interval::closed(350, 359),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union(&self, other: &Self) -> Selection<T> {
+        self.to_selection().union(&other.to_selection())
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// WrappingSelection
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of points over a circular domain.
+///
+/// A `WrappingSelection` is stored as a [`Selection`] restricted to its
+/// domain, and its intervals are merged across the end of the domain when
+/// iterated as [`WrappingInterval`]s.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`WrappingInterval`]: struct.WrappingInterval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::wrapping::WrappingInterval;
+/// # use normalize_interval::wrapping::WrappingSelection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// // Ownership ranges on a hash ring of 256 slots.
+/// let ring: Interval<u8> = Interval::closed(0, 255);
+/// let owned = WrappingSelection::from_intervals(ring, vec![
+///     WrappingInterval::new(ring, 250, 3),
+///     WrappingInterval::new(ring, 4, 10),
+/// ]);
+///
+/// assert_eq!(owned.interval_iter().collect::<Vec<_>>(), [
+///     WrappingInterval::new(ring, 250, 10),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WrappingSelection<T> {
+    /// The circular domain of the selection.
+    domain: Interval<T>,
+    /// The selected points of the domain.
+    selection: Selection<T>,
+}
+
+impl<T> WrappingSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new empty `WrappingSelection` over the given domain.
+    pub fn new(domain: Interval<T>) -> Self {
+        WrappingSelection {
+            domain,
+            selection: Selection::new(),
+        }
+    }
+
+    /// Constructs a new `WrappingSelection` over the given domain containing
+    /// all of its points.
+    pub fn full(domain: Interval<T>) -> Self {
+        WrappingSelection {
+            selection: Selection::from(domain.clone()),
+            domain,
+        }
+    }
+
+    /// Constructs a new `WrappingSelection` over the given domain containing
+    /// the points of the given `WrappingInterval`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `WrappingInterval`s has a different domain.
+    pub fn from_intervals<I>(domain: Interval<T>, intervals: I) -> Self
+        where I: IntoIterator<Item=WrappingInterval<T>>
+    {
+        let mut selection = Selection::new();
+        for interval in intervals {
+            assert!(interval.domain == domain,
+                "wrapping interval domain must match the selection domain");
+            for part in interval.to_selection().into_interval_iter() {
+                selection.union_in_place(part);
+            }
+        }
+        WrappingSelection { domain, selection }
+    }
+
+    /// Returns the circular domain of the `WrappingSelection`.
+    #[inline]
+    pub fn domain(&self) -> &Interval<T> {
+        &self.domain
+    }
+
+    /// Returns the `Selection` of the points of the domain contained in the
+    /// `WrappingSelection`.
+    #[inline]
+    pub fn as_selection(&self) -> &Selection<T> {
+        &self.selection
+    }
+
+    /// Returns `true` if the `WrappingSelection` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.selection.is_empty()
+    }
+
+    /// Returns `true` if the `WrappingSelection` contains every point of its
+    /// domain.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.selection == Selection::from(self.domain.clone())
+    }
+
+    /// Returns `true` if the `WrappingSelection` contains the given point.
+    #[inline]
+    pub fn contains(&self, point: &T) -> bool {
+        self.selection.contains(point)
+    }
+
+    /// Returns the `WrappingSelection` of the points of the domain not
+    /// contained in the `WrappingSelection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::wrapping::WrappingInterval;
+    /// # use normalize_interval::wrapping::WrappingSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees: Interval<u32> = Interval::right_open(0, 360);
+    /// let sel = WrappingSelection::from_intervals(degrees, vec![
+    ///     WrappingInterval::new(degrees, 10, 350),
+    /// ]);
+    ///
+    /// assert_eq!(sel.complement().interval_iter().collect::<Vec<_>>(), [
+    ///     WrappingInterval::new(degrees, 351, 9),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement(&self) -> Self {
+        WrappingSelection {
+            domain: self.domain.clone(),
+            selection: self.selection.complement_within(&self.domain),
+        }
+    }
+
+    /// Returns the `WrappingSelection` of the points contained in both
+    /// `WrappingSelection`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `WrappingSelection`s have different domains.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::wrapping::WrappingInterval;
+    /// # use normalize_interval::wrapping::WrappingSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees: Interval<u32> = Interval::right_open(0, 360);
+    /// let a = WrappingSelection::from_intervals(degrees, vec![
+    ///     WrappingInterval::new(degrees, 300, 60),
+    /// ]);
+    /// let b = WrappingSelection::from_intervals(degrees, vec![
+    ///     WrappingInterval::new(degrees, 340, 20),
+    ///     WrappingInterval::new(degrees, 50, 100),
+    /// ]);
+    ///
+    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(), [
+    ///     WrappingInterval::new(degrees, 50, 60),
+    ///     WrappingInterval::new(degrees, 340, 20),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.check_domain(other);
+        WrappingSelection {
+            domain: self.domain.clone(),
+            selection: self.selection.intersect(&other.selection),
+        }
+    }
+
+    /// Returns the `WrappingSelection` of the points contained in either
+    /// `WrappingSelection`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `WrappingSelection`s have different domains.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::wrapping::WrappingInterval;
+    /// # use normalize_interval::wrapping::WrappingSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees: Interval<u32> = Interval::right_open(0, 360);
+    /// let a = WrappingSelection::from_intervals(degrees, vec![
+    ///     WrappingInterval::new(degrees, 0, 10),
+    /// ]);
+    /// let b = WrappingSelection::from_intervals(degrees, vec![
+    ///     WrappingInterval::new(degrees, 330, 359),
+    /// ]);
+    ///
+    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(), [
+    ///     WrappingInterval::new(degrees, 330, 10),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.check_domain(other);
+        WrappingSelection {
+            domain: self.domain.clone(),
+            selection: self.selection.union(&other.selection),
+        }
+    }
+
+    /// Returns the `WrappingSelection` of the points contained in the
+    /// `WrappingSelection` but not in the other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `WrappingSelection`s have different domains.
+    pub fn minus(&self, other: &Self) -> Self {
+        self.check_domain(other);
+        WrappingSelection {
+            domain: self.domain.clone(),
+            selection: self.selection.minus(&other.selection),
+        }
+    }
+
+    /// Returns the `WrappingSelection` of the points contained in exactly one
+    /// of the `WrappingSelection`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `WrappingSelection`s have different domains.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.check_domain(other);
+        WrappingSelection {
+            domain: self.domain.clone(),
+            selection: self.selection.symmetric_difference(&other.selection),
+        }
+    }
+
+    /// Returns an iterator over the `WrappingInterval`s of the
+    /// `WrappingSelection`, in order of their start points. Intervals
+    /// touching both ends of the domain are merged into a single wrapping
+    /// interval, which is given last.
+    pub fn interval_iter(&self) -> impl Iterator<Item=WrappingInterval<T>> {
+        let mut parts: Vec<(T, T)> = self.selection.interval_iter()
+            .filter_map(|i| match (i.infimum(), i.supremum()) {
+                (Some(l), Some(u)) => Some((l, u)),
+                _                  => None,
+            })
+            .collect();
+
+        if parts.len() > 1 {
+            let first_at_start = parts[0].0 == self.domain_infimum();
+            let last_at_end = parts[parts.len() - 1].1 == self.domain_supremum();
+            if first_at_start && last_at_end {
+                let (_, end) = parts.remove(0);
+                let last = parts.len() - 1;
+                parts[last].1 = end;
+            }
+        }
+
+        let domain = self.domain.clone();
+        parts.into_iter()
+            .map(move |(start, end)| WrappingInterval {
+                domain: domain.clone(),
+                start,
+                end,
+            })
+    }
+
+    /// Returns the least point of the domain.
+    fn domain_infimum(&self) -> T {
+        self.domain.infimum().expect("nonempty selection domain")
+    }
+
+    /// Returns the greatest point of the domain.
+    fn domain_supremum(&self) -> T {
+        self.domain.supremum().expect("nonempty selection domain")
+    }
+
+    /// Panics if the `WrappingSelection`s have different domains.
+    fn check_domain(&self, other: &Self) {
+        assert!(self.domain == other.domain,
+            "wrapping selection domains must match");
+    }
+}
+
+impl<T> PartialEq for WrappingSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.domain == other.domain && self.selection == other.selection
+    }
+}
+
+impl<T> Eq for WrappingSelection<T>
+    where
+        T: IntervalPoint,
+        RawInterval<T>: Normalize,
+{}